@@ -0,0 +1,70 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+use std::time::{Duration, Instant};
+use ruistic::{Interpreter, Parser, Resolver, Scanner};
+
+const PROGRAMS: &[(&str, &str)] = &[
+    ("fibonacci", include_str!("../benches/fibonacci.ru")),
+    ("loops", include_str!("../benches/loops.ru")),
+    ("strings", include_str!("../benches/strings.ru")),
+    ("concat", include_str!("../benches/concat.ru")),
+    ("variables", include_str!("../benches/variables.ru")),
+];
+
+/// Size of the generated source used to check that scanning stays linear.
+const SYNTHETIC_BYTES: usize = 100 * 1024;
+
+struct Timings {
+    scan: Duration,
+    parse: Duration,
+    run: Duration,
+}
+
+fn time_program(src: &str) -> Timings {
+    let start = Instant::now();
+    let tokens = Scanner::new(src.to_string()).scan_tokens();
+    let scan = start.elapsed();
+
+    let start = Instant::now();
+    let statements = Parser::new(tokens).parse();
+    Resolver::new().resolve(&statements).expect("benchmark programs should resolve");
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    Interpreter::new().interpret(statements);
+    let run = start.elapsed();
+
+    Timings { scan, parse, run }
+}
+
+/// Builds a program of roughly `bytes` bytes from declarations, arithmetic,
+/// strings and comments, so every scanner path sees a large input.
+fn synthetic_source(bytes: usize) -> String {
+    let mut src = String::with_capacity(bytes + 64);
+    let mut i = 0;
+    while src.len() < bytes {
+        src.push_str(&format!("var value{} = ({} + 2.5) * 3 / 4 >= 1 and \"item {}\" != nil; // row {}\n", i, i, i, i));
+        i += 1;
+    }
+    src
+}
+
+fn millis(duration: Duration) -> String {
+    format!("{:.3}ms", duration.as_secs_f64() * 1000.0)
+}
+
+pub fn run_benchmarks() {
+    let mut report = Vec::new();
+    for (name, src) in PROGRAMS {
+        report.push((name.to_string(), time_program(src)));
+    }
+    report.push(("synthetic".to_string(), time_program(&synthetic_source(SYNTHETIC_BYTES))));
+
+    println!("{:<12} {:>12} {:>12} {:>12}", "program", "scan", "parse", "run");
+    for (name, timings) in report {
+        println!("{:<12} {:>12} {:>12} {:>12}",
+                 name, millis(timings.scan), millis(timings.parse), millis(timings.run));
+    }
+}
@@ -0,0 +1,206 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// Each limb holds nine decimal digits, which keeps printing trivial.
+const BASE: u64 = 1_000_000_000;
+
+/// Every integer up to this magnitude (2^53) is exact in an `f64`.
+const MAX_EXACT_F64: f64 = 9_007_199_254_740_992.0;
+
+/// Whether `number` is an integer that an `f64` represents exactly.
+pub fn is_exact_integer(number: f64) -> bool {
+    number.fract() == 0.0 && number.abs() <= MAX_EXACT_F64
+}
+
+/// An arbitrary-precision integer: a sign and base-10^9 limbs, least
+/// significant first, with no leading zero limbs. Zero is never negative.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BigInt {
+    negative: bool,
+    limbs: Vec<u32>,
+}
+
+impl BigInt {
+    /// Parses an optionally negative string of decimal digits.
+    pub fn parse(text: &str) -> Option<BigInt> {
+        let (negative, digits) = match text.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, text),
+        };
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let mut limbs = Vec::with_capacity(digits.len() / 9 + 1);
+        let mut end = digits.len();
+        while end > 0 {
+            let start = end.saturating_sub(9);
+            limbs.push(digits[start..end].parse().ok()?);
+            end = start;
+        }
+        Some(BigInt { negative, limbs }.normalized())
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// The bytes its digits take on the heap.
+    pub(crate) fn heap_size(&self) -> usize {
+        self.limbs.len() * std::mem::size_of::<u32>()
+    }
+
+    /// Converts an integral `f64`; the fractional part must already be zero.
+    pub fn from_f64(number: f64) -> BigInt {
+        BigInt::parse(&format!("{:.0}", number)).unwrap_or_else(|| BigInt { negative: false, limbs: Vec::new() })
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        self.to_string().parse().unwrap_or(f64::NAN)
+    }
+
+    /// The value as an `f64` if it converts without losing precision.
+    pub fn to_exact_f64(&self) -> Option<f64> {
+        let number = self.to_f64();
+        (number.abs() <= MAX_EXACT_F64).then_some(number)
+    }
+
+    fn normalized(mut self) -> BigInt {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+        if self.limbs.is_empty() {
+            self.negative = false;
+        }
+        self
+    }
+}
+
+fn add_magnitudes(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut sum = Vec::with_capacity(a.len().max(b.len()) + 1);
+    let mut carry = 0;
+    for i in 0..a.len().max(b.len()) {
+        let total = *a.get(i).unwrap_or(&0) as u64 + *b.get(i).unwrap_or(&0) as u64 + carry;
+        sum.push((total % BASE) as u32);
+        carry = total / BASE;
+    }
+    if carry > 0 {
+        sum.push(carry as u32);
+    }
+    sum
+}
+
+/// `a - b` for magnitudes where `a >= b`.
+fn sub_magnitudes(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut difference = Vec::with_capacity(a.len());
+    let mut borrow = 0;
+    for (i, &limb) in a.iter().enumerate() {
+        let subtrahend = *b.get(i).unwrap_or(&0) as i64 + borrow;
+        let mut total = limb as i64 - subtrahend;
+        borrow = 0;
+        if total < 0 {
+            total += BASE as i64;
+            borrow = 1;
+        }
+        difference.push(total as u32);
+    }
+    difference
+}
+
+fn compare_magnitudes(a: &[u32], b: &[u32]) -> Ordering {
+    a.len().cmp(&b.len()).then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt { negative: !self.negative, limbs: self.limbs.clone() }.normalized()
+    }
+}
+
+impl Add for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, other: &BigInt) -> BigInt {
+        if self.negative == other.negative {
+            return BigInt { negative: self.negative, limbs: add_magnitudes(&self.limbs, &other.limbs) }.normalized();
+        }
+        match compare_magnitudes(&self.limbs, &other.limbs) {
+            Ordering::Less => BigInt { negative: other.negative, limbs: sub_magnitudes(&other.limbs, &self.limbs) },
+            _ => BigInt { negative: self.negative, limbs: sub_magnitudes(&self.limbs, &other.limbs) },
+        }.normalized()
+    }
+}
+
+impl Sub for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, other: &BigInt) -> BigInt {
+        self + &-other
+    }
+}
+
+impl Mul for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, other: &BigInt) -> BigInt {
+        let mut product = vec![0u64; self.limbs.len() + other.limbs.len()];
+        for (i, &a) in self.limbs.iter().enumerate() {
+            let mut carry = 0;
+            for (j, &b) in other.limbs.iter().enumerate() {
+                let total = product[i + j] + a as u64 * b as u64 + carry;
+                product[i + j] = total % BASE;
+                carry = total / BASE;
+            }
+            product[i + other.limbs.len()] += carry;
+        }
+        let limbs = product.into_iter().map(|limb| limb as u32).collect();
+        BigInt { negative: self.negative != other.negative, limbs }.normalized()
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => compare_magnitudes(&self.limbs, &other.limbs),
+            (true, true) => compare_magnitudes(&other.limbs, &self.limbs),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some((most, rest)) = self.limbs.split_last() else {
+            return write!(f, "0");
+        };
+        if self.negative {
+            write!(f, "-")?;
+        }
+        write!(f, "{}", most)?;
+        for limb in rest.iter().rev() {
+            write!(f, "{:09}", limb)?;
+        }
+        Ok(())
+    }
+}
+
+/// Serialized as its decimal digits, since no JSON number holds it exactly.
+#[cfg(feature = "serde")]
+impl serde::Serialize for BigInt {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+use crate::error::Error;
+use crate::function::Function;
+use crate::interpreter::Interpreter;
+use crate::token::{Token, Value};
+
+/// A class declaration. Calling it constructs a new `Instance`.
+pub struct Class {
+    name: String,
+    methods: HashMap<String, Rc<Function>>,
+}
+
+impl Class {
+    pub fn new(name: String, methods: HashMap<String, Rc<Function>>) -> Self {
+        Self { name, methods }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn find_method(&self, name: &str) -> Option<&Rc<Function>> {
+        self.methods.get(name)
+    }
+
+    /// The number of arguments a call takes: those of `init`, or none.
+    pub fn arity(&self) -> usize {
+        self.find_method("init").map_or(0, |init| init.arity())
+    }
+}
+
+impl fmt::Debug for Class {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<class {}>", self.name)
+    }
+}
+
+impl PartialEq for Class {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+/// An object created by calling a class. Clones share the same field store.
+#[derive(Clone)]
+pub struct Instance {
+    class: Rc<Class>,
+    fields: Rc<RefCell<HashMap<String, Value>>>,
+}
+
+impl Instance {
+    pub fn new(class: Rc<Class>) -> Self {
+        Self { class, fields: Rc::new(RefCell::new(HashMap::new())) }
+    }
+
+    pub fn class(&self) -> &Rc<Class> {
+        &self.class
+    }
+
+    /// Looks up a field, falling back to a method of the class bound to this
+    /// instance, and then to the class's `__missing__` method, whose result
+    /// for the property name stands in for the property.
+    pub fn get(&self, name: &Token, interpreter: &mut Interpreter) -> Result<Value, Error> {
+        if let Some(value) = self.fields.borrow().get(name.get_lexeme()) {
+            return Ok(value.clone());
+        }
+        if let Some(method) = self.class.find_method(name.get_lexeme()) {
+            return Ok(Value::Function(Rc::new(method.bind(self.clone()))));
+        }
+        match self.class.find_method("__missing__") {
+            Some(missing) if missing.arity() == 1 => {
+                missing.bind(self.clone()).call(interpreter, vec![Value::String(name.get_lexeme().to_string().into())])
+            },
+            Some(_) => Err(Error::runtime("'__missing__' must take one parameter, the property name", name)),
+            None => Err(Error::runtime(format!("Undefined property '{}'", name.get_lexeme()), name)),
+        }
+    }
+
+    pub fn set(&self, name: &Token, value: Value) {
+        self.fields.borrow_mut().insert(name.get_lexeme().to_string(), value);
+    }
+}
+
+impl fmt::Debug for Instance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<{} instance>", self.class.name)
+    }
+}
+
+impl PartialEq for Instance {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.fields, &other.fields)
+    }
+}
@@ -2,51 +2,116 @@
 // This file is part of Ruistic, a custom programming language interpreter.
 // See LICENSE file for license information.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
 use std::cell::RefCell;
 use crate::expression::Expr;
+use crate::error::Error;
 use crate::token::*;
 
 #[derive(Debug, Clone)]
 pub struct Environment {
     values: HashMap<String, Value>,
+    /// The names in `values` declared with `const`.
+    constants: HashSet<String>,
     parent: Option<Rc<RefCell<Environment>>>,
 }
 
 impl Environment {
     pub fn new() -> Self {
-        Self { values: HashMap::new(), parent: None }
+        Self { values: HashMap::new(), constants: HashSet::new(), parent: None }
     }
 
     pub fn enclose(parent: Rc<RefCell<Environment>>) -> Self {
-        Self { values: HashMap::new(), parent: Some(parent) }
+        Self { values: HashMap::new(), constants: HashSet::new(), parent: Some(parent) }
     }
 
+    /// Number of enclosing environments above this one; the global scope is 0.
+    pub fn depth(&self) -> usize {
+        match &self.parent {
+            Some(parent) => 1 + parent.borrow().depth(),
+            None => 0,
+        }
+    }
+
+    /// Defines a variable, replacing any variable or constant of the same name
+    /// in this environment.
     pub fn define(&mut self, name: String, value: Value) {
+        self.constants.remove(&name);
         self.values.insert(name, value);
     }
 
-    pub fn assign(&mut self, name: &Token, value: Value) -> Result<Value, String> {
-        if self.values.contains_key(name.get_lexeme()) {
-            self.values.insert(name.get_lexeme().to_string(), value.clone());
+    /// Defines a variable that `assign` and `assign_at` refuse to change.
+    pub fn define_constant(&mut self, name: String, value: Value) {
+        self.values.insert(name.clone(), value);
+        self.constants.insert(name);
+    }
+
+    pub fn assign(&mut self, name: &Token, value: Value) -> Result<Value, Error> {
+        if self.constants.contains(name.get_lexeme()) {
+            return Err(constant(name));
+        }
+        // Overwrite in place: no second lookup and no key allocation.
+        if let Some(slot) = self.values.get_mut(name.get_lexeme()) {
+            *slot = value.clone();
             return Ok(value);
         }
         if let Some(parent) = &self.parent {
             return parent.borrow_mut().assign(name, value);
         }
-        Err(format!("Runtime error: Variable {} not defined", name.get_lexeme()))
+        Err(undefined(name))
+    }
+
+    /// Assigns `name` in the environment `depth` levels up, where the
+    /// resolver found its declaration.
+    pub fn assign_at(&mut self, depth: usize, name: &Token, value: Value) -> Result<Value, Error> {
+        if depth > 0 {
+            return match &self.parent {
+                Some(parent) => parent.borrow_mut().assign_at(depth - 1, name, value),
+                None => Err(undefined(name)),
+            };
+        }
+        if self.constants.contains(name.get_lexeme()) {
+            return Err(constant(name));
+        }
+        match self.values.get_mut(name.get_lexeme()) {
+            Some(slot) => {
+                *slot = value.clone();
+                Ok(value)
+            },
+            None => Err(undefined(name)),
+        }
     }
 
-    pub fn get(&self, name: &Token) -> Result<Value, String> {
+    pub fn get(&self, name: &Token) -> Result<Value, Error> {
         match self.values.get(name.get_lexeme()) {
             Some(existing_value) => Ok(existing_value.clone()),
             None => {
                 if let Some(parent) = &self.parent {
                     return parent.borrow().get(name);
                 }
-                Err(format!("Runtime error: Variable {} not defined", name.get_lexeme()))
+                Err(undefined(name))
             }
         }
     }
+
+    /// Reads `name` from the environment `depth` levels up, where the
+    /// resolver found its declaration.
+    pub fn get_at(&self, depth: usize, name: &Token) -> Result<Value, Error> {
+        if depth > 0 {
+            return match &self.parent {
+                Some(parent) => parent.borrow().get_at(depth - 1, name),
+                None => Err(undefined(name)),
+            };
+        }
+        self.values.get(name.get_lexeme()).cloned().ok_or_else(|| undefined(name))
+    }
+}
+
+fn undefined(name: &Token) -> Error {
+    Error::runtime(format!("Undefined variable '{}'", name.get_lexeme()), name)
+}
+
+fn constant(name: &Token) -> Error {
+    Error::runtime(format!("Cannot assign to constant '{}'.", name.get_lexeme()), name)
 }
@@ -0,0 +1,78 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+use std::fmt;
+use crate::token::Token;
+
+/// An error from scanning, parsing or running a program, with where in the
+/// source it happened. `Display` appends the location to the message.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    Scan { message: String, line: usize },
+    Parse { message: String, line: usize, column: usize },
+    /// Found by the resolver after parsing, before anything runs.
+    Resolve { message: String, line: usize, column: usize },
+    /// `line` is `None` for errors raised outside any source, such as a host
+    /// calling a script function through `Interpreter::call_value`.
+    Runtime { message: String, line: Option<usize> },
+}
+
+impl Error {
+    /// A parse error located at `token`.
+    pub fn parse(message: impl Into<String>, token: &Token) -> Self {
+        Error::Parse { message: message.into(), line: token.get_line(), column: token.get_column() }
+    }
+
+    /// A resolver error located at `token`.
+    pub fn resolve(message: impl Into<String>, token: &Token) -> Self {
+        Error::Resolve { message: message.into(), line: token.get_line(), column: token.get_column() }
+    }
+
+    /// A runtime error located at `token`.
+    pub fn runtime(message: impl Into<String>, token: &Token) -> Self {
+        Error::Runtime { message: message.into(), line: Some(token.get_line()) }
+    }
+
+    pub fn message(&self) -> &str {
+        match self {
+            Error::Scan { message, .. } | Error::Parse { message, .. } | Error::Resolve { message, .. }
+            | Error::Runtime { message, .. } => message,
+        }
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        match self {
+            Error::Scan { line, .. } | Error::Parse { line, .. } | Error::Resolve { line, .. } => Some(*line),
+            Error::Runtime { line, .. } => *line,
+        }
+    }
+}
+
+impl Error {
+    /// The error as the command line reports it: prefixed with the phase
+    /// that found it, except for scan errors.
+    pub fn report(&self) -> String {
+        match self {
+            Error::Scan { .. } => self.to_string(),
+            Error::Parse { .. } => format!("Parsing error: {}", self),
+            Error::Resolve { .. } => format!("Resolution error: {}", self),
+            Error::Runtime { .. } => format!("Runtime error: {}", self),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Scan { message, line } => write!(f, "{} at line {}", message, line),
+            Error::Parse { message, line, column } | Error::Resolve { message, line, column } => {
+                write!(f, "{} at line {}, column {}", message, line, column)
+            },
+            Error::Runtime { message, line: Some(line) } => write!(f, "{} at line {}", message, line),
+            Error::Runtime { message, line: None } => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
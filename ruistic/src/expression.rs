@@ -2,14 +2,82 @@
 // This file is part of Ruistic, a custom programming language interpreter.
 // See LICENSE file for license information.
 
-use crate::token::{Token, Value};
+use std::cell::Cell;
+use std::fmt;
+use crate::statement::Stmt;
+use crate::token::{Token, TokenType, Value};
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Expr {
     Literal(Value),
     Unary { operator: Token, right: Box<Expr> },
     Binary { left: Box<Expr>, operator: Token, right: Box<Expr> },
+    Logical { left: Box<Expr>, operator: Token, right: Box<Expr> },
+    Ternary { condition: Box<Expr>, then_branch: Box<Expr>, else_branch: Box<Expr> },
     Grouping(Box<Expr>),
-    Variable(Token),
-    Assign { name: Token, value: Box<Expr> },
+    Call { callee: Box<Expr>, paren: Token, arguments: Vec<Expr> },
+    Get { object: Box<Expr>, name: Token },
+    Set { object: Box<Expr>, name: Token, value: Box<Expr> },
+    /// `[a, b, c]`.
+    ListLiteral(Vec<Expr>),
+    /// `object[index]`; `bracket` is the `[`, for error locations.
+    Index { object: Box<Expr>, bracket: Token, index: Box<Expr> },
+    IndexSet { object: Box<Expr>, bracket: Token, index: Box<Expr>, value: Box<Expr> },
+    This(Token),
+    /// `loop { ... }`, which repeats its body until a `break` supplies the result.
+    Loop(Box<Stmt>),
+    /// `depth` is set by the resolver: how many scopes out the variable is
+    /// declared, or `None` for a global.
+    Variable { name: Token, depth: Cell<Option<usize>> },
+    Assign { name: Token, value: Box<Expr>, depth: Cell<Option<usize>> },
+}
+
+/// The expression as an S-expression, with every operator before its
+/// operands, as in `(+ 1 (* 2 3))`, so the grouping the parser chose is
+/// explicit.
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expr::Literal(Value::String(s)) => write!(f, "{:?}", s),
+            Expr::Literal(value) => write!(f, "{}", value),
+            Expr::Unary { operator, right } => write!(f, "({} {})", operator.get_lexeme(), right),
+            Expr::Binary { left, operator, right } => write!(f, "({} {} {})", operator.get_lexeme(), left, right),
+            // `&&` and `||` show as the keywords they mean.
+            Expr::Logical { left, operator, right } => {
+                let keyword = if operator.get_type() == TokenType::AND { "and" } else { "or" };
+                write!(f, "({} {} {})", keyword, left, right)
+            },
+            Expr::Ternary { condition, then_branch, else_branch } => {
+                write!(f, "(?: {} {} {})", condition, then_branch, else_branch)
+            },
+            Expr::Grouping(expr) => write!(f, "(group {})", expr),
+            Expr::Call { callee, arguments, .. } => {
+                write!(f, "(call {}", callee)?;
+                spaced(f, arguments)?;
+                write!(f, ")")
+            },
+            Expr::Get { object, name } => write!(f, "(. {} {})", object, name.get_lexeme()),
+            Expr::Set { object, name, value } => write!(f, "(= (. {} {}) {})", object, name.get_lexeme(), value),
+            Expr::ListLiteral(elements) => {
+                write!(f, "(list")?;
+                spaced(f, elements)?;
+                write!(f, ")")
+            },
+            Expr::Index { object, index, .. } => write!(f, "([] {} {})", object, index),
+            Expr::IndexSet { object, index, value, .. } => write!(f, "(= ([] {} {}) {})", object, index, value),
+            Expr::This(_) => write!(f, "this"),
+            Expr::Loop(body) => write!(f, "(loop {})", body),
+            Expr::Variable { name, .. } => write!(f, "{}", name.get_lexeme()),
+            Expr::Assign { name, value, .. } => write!(f, "(= {} {})", name.get_lexeme(), value),
+        }
+    }
+}
+
+/// Writes each item preceded by a space, for the operands of an S-expression.
+pub(crate) fn spaced<T: fmt::Display>(f: &mut fmt::Formatter<'_>, items: &[T]) -> fmt::Result {
+    for item in items {
+        write!(f, " {}", item)?;
+    }
+    Ok(())
 }
@@ -0,0 +1,100 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+use crate::class::Instance;
+use crate::environment::Environment;
+use crate::error::Error;
+use crate::interpreter::{ControlFlow, Interpreter};
+use crate::statement::Stmt;
+use crate::token::{Token, TokenType, Value};
+
+/// A user-defined function together with the environment it was declared in.
+pub struct Function {
+    name: Token,
+    params: Vec<Token>,
+    body: Rc<Vec<Stmt>>,
+    closure: Rc<RefCell<Environment>>,
+    /// Leading arguments already supplied by partial application.
+    applied: Vec<Value>,
+    /// Whether this is a class's `init` method, which always returns `this`.
+    initializer: bool,
+}
+
+impl Function {
+    pub fn new(name: Token, params: Vec<Token>, body: Rc<Vec<Stmt>>, closure: Rc<RefCell<Environment>>) -> Self {
+        Self { name, params, body, closure, applied: Vec::new(), initializer: false }
+    }
+
+    /// Marks this method as its class's `init`.
+    pub fn into_initializer(mut self) -> Self {
+        self.initializer = true;
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.get_lexeme()
+    }
+
+    pub fn arity(&self) -> usize {
+        self.params.len() - self.applied.len()
+    }
+
+    /// Returns a copy of this method whose closure defines `this` as `instance`.
+    pub fn bind(&self, instance: Instance) -> Function {
+        let mut environment = Environment::enclose(self.closure.clone());
+        environment.define("this".to_string(), Value::Instance(instance));
+        Function { closure: Rc::new(RefCell::new(environment)), ..self.partial(Vec::new()) }
+    }
+
+    /// Returns a copy of this function with `arguments` fixed after any already applied.
+    pub fn partial(&self, arguments: Vec<Value>) -> Function {
+        let mut applied = self.applied.clone();
+        applied.extend(arguments);
+        Function {
+            name: self.name.clone(),
+            params: self.params.clone(),
+            body: self.body.clone(),
+            closure: self.closure.clone(),
+            applied,
+            initializer: self.initializer,
+        }
+    }
+
+    pub fn call(&self, interpreter: &mut Interpreter, arguments: Vec<Value>) -> Result<Value, Error> {
+        let mut environment = Environment::enclose(self.closure.clone());
+        let arguments = self.applied.iter().cloned().chain(arguments);
+        for (param, argument) in self.params.iter().zip(arguments) {
+            environment.define(param.get_lexeme().to_string(), argument);
+        }
+        let result = interpreter.execute_block(&self.body, Rc::new(RefCell::new(environment)));
+        if self.initializer && matches!(result, Ok(()) | Err(ControlFlow::Return(_))) {
+            let this = Token::new(TokenType::THIS, "this".to_string(), None, self.name.get_line(), self.name.get_column());
+            return self.closure.borrow().get_at(0, &this);
+        }
+        match result {
+            Ok(()) => Ok(Value::Nil),
+            Err(ControlFlow::Return(value)) => Ok(value),
+            Err(ControlFlow::Error(error)) => Err(error),
+            Err(ControlFlow::Break(_) | ControlFlow::Continue) => {
+                unreachable!("the parser rejects 'break' and 'continue' outside of a loop")
+            },
+        }
+    }
+}
+
+impl fmt::Debug for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<fn {}>", self.name())
+    }
+}
+
+/// Functions compare by identity: two values are equal only if they are the same declaration instance.
+impl PartialEq for Function {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
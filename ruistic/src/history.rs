@@ -0,0 +1,40 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+use std::fmt;
+
+/// The inputs entered at the REPL, oldest first. A statement continued
+/// over several lines is one entry.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct History {
+    entries: Vec<String>,
+}
+
+impl History {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records an input; an empty one is left out.
+    pub fn push(&mut self, input: &str) {
+        if !input.is_empty() {
+            self.entries.push(input.to_string());
+        }
+    }
+
+    pub fn entries(&self) -> &[String] {
+        &self.entries
+    }
+}
+
+/// How `:history` lists the entries: one per line, numbered from 1. An
+/// empty history prints nothing.
+impl fmt::Display for History {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, entry) in self.entries.iter().enumerate() {
+            writeln!(f, "{:>4}  {}", i + 1, entry)?;
+        }
+        Ok(())
+    }
+}
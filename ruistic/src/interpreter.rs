@@ -2,97 +2,390 @@
 // This file is part of Ruistic, a custom programming language interpreter.
 // See LICENSE file for license information.
 
-use std::rc::Rc;
+use std::rc::{Rc, Weak};
 use std::cell::RefCell;
+use std::collections::HashMap;
+use std::io::{self, Write};
+use crate::bigint::{self, BigInt};
+use crate::class::{Class, Instance};
 use crate::environment::Environment;
+use crate::error::Error;
 use crate::expression::Expr;
+use crate::function::Function;
+use crate::native::{self, Native};
 use crate::statement::Stmt;
 use crate::token::{Value, Token, TokenType};
+
+/// Non-local control flow that unwinds through `execute` on the `Err` channel.
+#[derive(Debug)]
+pub(crate) enum ControlFlow {
+    Return(Value),
+    /// Carries the value of `break value;` out of a `loop` expression.
+    Break(Option<Value>),
+    Continue,
+    /// A runtime error; it unwinds to `interpret`, which reports it and stops.
+    Error(Error),
+}
+
+impl From<Error> for ControlFlow {
+    fn from(error: Error) -> Self {
+        ControlFlow::Error(error)
+    }
+}
+
+/// A sink for `print` output or runtime errors. It is shared so the host
+/// keeps a handle to read back what was written.
+pub type Output = Rc<RefCell<dyn Write>>;
+
 pub struct Interpreter {
     environment: Rc<RefCell<Environment>>,
+    /// The outermost environment, where unresolved variables live.
+    globals: Rc<RefCell<Environment>>,
+    output: Output,
+    error_output: Output,
+    curry: bool,
+    lenient_nil: bool,
+    promote: bool,
+    /// Executions per source line, when profiling.
+    profile: Option<HashMap<usize, usize>>,
+    /// Lists `freeze` has made read-only, keyed by address. The weak
+    /// reference keeps a freed list's address from being reused.
+    frozen: HashMap<*const RefCell<Vec<Value>>, Weak<RefCell<Vec<Value>>>>,
+}
+
+impl Default for Interpreter {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Interpreter {
     pub fn new() -> Self {
-        Self {
-            environment: Rc::new(RefCell::new(Environment::new())),
+        Self::with_outputs(Rc::new(RefCell::new(io::stdout())), Rc::new(RefCell::new(io::stderr())))
+    }
+
+    /// Writes `print` output and runtime errors to `output` instead of
+    /// stdout and stderr.
+    pub fn with_output(output: Output) -> Self {
+        Self::with_outputs(output.clone(), output)
+    }
+
+    /// Writes `print` output to `output` and runtime errors to `error_output`.
+    pub fn with_outputs(output: Output, error_output: Output) -> Self {
+        let globals = Rc::new(RefCell::new(Environment::new()));
+        let mut interpreter = Self {
+            environment: globals.clone(),
+            globals,
+            output,
+            error_output,
+            curry: false,
+            lenient_nil: false,
+            promote: false,
+            profile: None,
+            frozen: HashMap::new(),
+        };
+        for native in native::natives() {
+            interpreter.define_native(native);
         }
+        interpreter
+    }
+
+    /// Defines a native function in the global scope, replacing any global
+    /// of the same name, so a host can give scripts functions of its own.
+    pub fn define_native(&mut self, native: Native) {
+        self.globals.borrow_mut().define(native.name().to_string(), Value::Native(Rc::new(native)));
+    }
+
+    /// Writes text to the `print` output as it is, flushing it so the host
+    /// sees it at once.
+    pub(crate) fn write_output(&mut self, text: &str) -> Result<(), String> {
+        let mut output = self.output.borrow_mut();
+        output.write_all(text.as_bytes())
+            .and_then(|()| output.flush())
+            .map_err(|error| format!("Could not write output: {}", error))
+    }
+
+    /// Makes a list and every list nested in it read-only.
+    pub(crate) fn freeze(&mut self, list: &Rc<RefCell<Vec<Value>>>) {
+        if self.frozen.insert(Rc::as_ptr(list), Rc::downgrade(list)).is_some() {
+            return;
+        }
+        for element in list.borrow().iter() {
+            if let Value::List(element) = element {
+                self.freeze(element);
+            }
+        }
+    }
+
+    /// In curry mode, calling a function with fewer arguments than its arity
+    /// returns a new function waiting for the rest instead of failing.
+    pub fn set_curry(&mut self, enabled: bool) {
+        self.curry = enabled;
+    }
+
+    /// In lenient-nil mode, calling `nil`, reading a property of `nil` or
+    /// indexing `nil` yields `nil` instead of a runtime error, so `a.b.c()`
+    /// or `a.b[0]` on missing data quietly produces `nil`.
+    pub fn set_lenient_nil(&mut self, enabled: bool) {
+        self.lenient_nil = enabled;
     }
 
     pub fn interpret(&mut self, statements: Vec<Stmt>) {
+        if let Err(error) = self.try_interpret(statements) {
+            // Nowhere is left to report a failure to write the report.
+            let _ = writeln!(self.error_output.borrow_mut(), "{}", error.report());
+        }
+    }
+
+    /// Runs the statements up to the first runtime error, which is returned
+    /// instead of reported.
+    pub fn try_interpret(&mut self, statements: Vec<Stmt>) -> Result<(), Error> {
+        let depth = self.scope_depth();
         for stmt in statements {
-            self.execute(&stmt);
+            // The parser rejects `return`, `break` and `continue` outside a function
+            // or loop, so only an error unwinds this far.
+            let result = self.execute(&stmt);
+            debug_assert_eq!(self.scope_depth(), depth, "a statement leaked a scope");
+            if let Err(ControlFlow::Error(error)) = result {
+                return Err(error);
+            }
         }
+        Ok(())
     }
 
-    fn execute_block(&mut self, stmts: &[Stmt], new_env: Rc<RefCell<Environment>>) {
-        let previous = self.environment.clone();
+    /// In promote mode, integer `+`, `-` and `*` whose result an `f64` can't
+    /// hold exactly produce a big integer instead of a rounded number.
+    pub fn set_promote(&mut self, enabled: bool) {
+        self.promote = enabled;
+    }
+
+    /// When profiling, every executed statement is counted against its line;
+    /// `line_counts` reports the totals.
+    pub fn set_profile(&mut self, enabled: bool) {
+        self.profile = enabled.then(HashMap::new);
+    }
+
+    /// `(line, executions)` pairs, most executed first and ties by line, or
+    /// nothing if profiling is off.
+    pub fn line_counts(&self) -> Vec<(usize, usize)> {
+        let mut counts: Vec<(usize, usize)> = self.profile.iter().flatten().map(|(&line, &count)| (line, count)).collect();
+        counts.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        counts
+    }
 
-        for stmt in stmts {
-            self.execute(stmt);
+    /// Calls a script value with already-evaluated arguments, checking arity
+    /// as a call expression would, so host code can invoke callbacks it was
+    /// handed (a comparator, a mapping function).
+    pub fn call_value(&mut self, callee: &Value, arguments: Vec<Value>) -> Result<Value, Error> {
+        self.call(callee, arguments, None)
+    }
+
+    /// Shared by call expressions and `call_value`; `paren` locates errors
+    /// when the call comes from source.
+    fn call(&mut self, callee: &Value, arguments: Vec<Value>, paren: Option<&Token>) -> Result<Value, Error> {
+        let error = |message: String| Error::Runtime { message, line: paren.map(Token::get_line) };
+        match callee {
+            Value::Function(function) => {
+                if self.curry && arguments.len() < function.arity() {
+                    return Ok(Value::Function(Rc::new(function.partial(arguments))));
+                }
+                if arguments.len() != function.arity() {
+                    return Err(error(format!("Expected {} arguments but got {}", function.arity(), arguments.len())));
+                }
+                function.call(self, arguments)
+            },
+            Value::Native(native) => {
+                if !(native.min_arity()..=native.arity()).contains(&arguments.len()) {
+                    let expected = if native.min_arity() == native.arity() {
+                        native.arity().to_string()
+                    } else {
+                        format!("{} to {}", native.min_arity(), native.arity())
+                    };
+                    return Err(error(format!("Expected {} arguments but got {}", expected, arguments.len())));
+                }
+                native.call(self, &arguments).map_err(error)
+            },
+            Value::Class(class) => {
+                if arguments.len() != class.arity() {
+                    return Err(error(format!("Expected {} arguments but got {}", class.arity(), arguments.len())));
+                }
+                let instance = Instance::new(class.clone());
+                if let Some(init) = class.find_method("init") {
+                    init.bind(instance.clone()).call(self, arguments)?;
+                }
+                Ok(Value::Instance(instance))
+            },
+            Value::Nil if self.lenient_nil => Ok(Value::Nil),
+            _ => Err(error("Can only call functions and classes".to_string())),
         }
+    }
+
+    /// Evaluates a single expression against the global environment.
+    pub fn eval_expression(&mut self, expr: &Expr) -> Result<Value, Error> {
+        self.evaluate(expr).map_err(|flow| match flow {
+            ControlFlow::Error(error) => error,
+            _ => unreachable!("the parser rejects 'return' outside a function and 'break' outside a loop"),
+        })
+    }
+
+    /// How many scopes enclose the current environment; 0 at the top level.
+    pub fn scope_depth(&self) -> usize {
+        self.environment.borrow().depth()
+    }
+
+    pub(crate) fn execute_block(&mut self, stmts: &[Stmt], new_env: Rc<RefCell<Environment>>) -> Result<(), ControlFlow> {
+        let previous = std::mem::replace(&mut self.environment, new_env);
+        let result = stmts.iter().try_for_each(|stmt| self.execute(stmt));
         self.environment = previous;
+        result
     }
 
-    fn execute(&mut self, stmt: &Stmt) {
+    fn execute(&mut self, stmt: &Stmt) -> Result<(), ControlFlow> {
+        if let Some(profile) = &mut self.profile {
+            *profile.entry(stmt.line()).or_insert(0) += 1;
+        }
         match stmt {
-            Stmt::Expr(expr) => {
-                let _ = self.evaluate(expr);
+            Stmt::Expr(expr, _) => {
+                self.evaluate(expr)?;
             }
-            Stmt::Print(expr) => {
-                let value = self.evaluate(expr);
-                match value {
-                    Ok(value) => {
-                        println!("{}", self.stringify(value))
-                    },
-                    Err(error) => {
-                        eprintln!("Runtime error: {}", error);
+            Stmt::Print { values, newline, line } => {
+                // Everything is evaluated first, so an error prints nothing.
+                let mut text = String::new();
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        text.push(' ');
                     }
+                    text.push_str(&self.evaluate(value)?.to_string());
                 }
+                if *newline {
+                    text.push('\n');
+                }
+                self.write_output(&text).map_err(|message| ControlFlow::Error(Error::Runtime { message, line: Some(*line) }))?;
             },
             Stmt::Var(name, value) => {
                 let value = if let Some(expr) = value {
-                    self.evaluate(expr).unwrap_or(Value::Nil)
+                    self.evaluate(expr)?
                 } else {
                     Value::Nil
                 };
                 self.environment.borrow_mut().define(name.get_lexeme().to_string(), value);
             },
-            Stmt::Block(stmts) => {
-                let new_env = Rc::new(RefCell::new(Environment::enclose(self.environment.clone())));
-                self.execute_block(stmts, new_env);
-            },
-            Stmt::If {condition, then_branch, else_branch} => {
-                match self.evaluate(condition) {
-                    Ok(value) => {
-                        if self.is_truthy(&value) {
-                            self.execute(then_branch);
-                        } else if let Some(else_stmt) = else_branch {
-                            self.execute(else_stmt);
+            Stmt::Const(name, value) => {
+                let value = self.evaluate(value)?;
+                self.environment.borrow_mut().define_constant(name.get_lexeme().to_string(), value);
+            },
+            Stmt::Function { name, params, body } => {
+                let function = Function::new(name.clone(), params.clone(), body.clone(), self.environment.clone());
+                self.environment.borrow_mut().define(name.get_lexeme().to_string(), Value::Function(Rc::new(function)));
+            },
+            Stmt::Class { name, methods } => {
+                let mut functions = HashMap::new();
+                for method in methods {
+                    if let Stmt::Function { name, params, body } = method {
+                        let mut function = Function::new(name.clone(), params.clone(), body.clone(), self.environment.clone());
+                        if name.get_lexeme() == "init" {
+                            function = function.into_initializer();
                         }
+                        functions.insert(name.get_lexeme().to_string(), Rc::new(function));
+                    }
+                }
+                let class = Class::new(name.get_lexeme().to_string(), functions);
+                self.environment.borrow_mut().define(name.get_lexeme().to_string(), Value::Class(Rc::new(class)));
+            },
+            Stmt::Return { value, .. } => {
+                let value = match value {
+                    Some(expr) => self.evaluate(expr)?,
+                    None => Value::Nil,
+                };
+                return Err(ControlFlow::Return(value));
+            },
+            Stmt::Block(stmts, _) => {
+                let new_env = Rc::new(RefCell::new(Environment::enclose(self.environment.clone())));
+                self.execute_block(stmts, new_env)?;
+            },
+            Stmt::Break(value, _) => {
+                let value = match value {
+                    Some(expr) => Some(self.evaluate(expr)?),
+                    None => None,
+                };
+                return Err(ControlFlow::Break(value));
+            },
+            Stmt::Continue(_) => return Err(ControlFlow::Continue),
+            Stmt::If {condition, then_branch, else_branch, .. } => {
+                let value = self.evaluate(condition)?;
+                if self.is_truthy(&value) {
+                    self.execute(then_branch)?;
+                } else if let Some(else_stmt) = else_branch {
+                    self.execute(else_stmt)?;
+                }
+            },
+            Stmt::While {condition, body, increment, .. } => {
+                loop {
+                    let value = self.evaluate(condition)?;
+                    if !self.is_truthy(&value) {
+                        break;
+                    }
+                    match self.execute(body) {
+                        Err(ControlFlow::Break(_)) => break,
+                        Err(ControlFlow::Continue) => {},
+                        result => result?,
                     }
-                    Err(error) => {
-                        eprintln!("Runtime error in if statement: {}", error);
+                    if let Some(increment) = increment {
+                        self.evaluate(increment)?;
                     }
                 }
             },
-            Stmt::While {condition, body } => {
-                while let Ok(value) = self.evaluate(condition) {
+            Stmt::DoWhile { body, condition, .. } => {
+                loop {
+                    match self.execute(body) {
+                        Err(ControlFlow::Break(_)) => break,
+                        Err(ControlFlow::Continue) => {},
+                        result => result?,
+                    }
+                    let value = self.evaluate(condition)?;
                     if !self.is_truthy(&value) {
                         break;
                     }
-                    self.execute(body);
+                }
+            },
+            Stmt::Switch { subject, cases, default, .. } => {
+                let subject = self.evaluate(subject)?;
+                let mut arm = default.as_ref();
+                for (value, body) in cases {
+                    if self.evaluate(value)? == subject {
+                        arm = Some(body);
+                        break;
+                    }
+                }
+                if let Some(body) = arm {
+                    let new_env = Rc::new(RefCell::new(Environment::enclose(self.environment.clone())));
+                    self.execute_block(body, new_env)?;
+                }
+            },
+            Stmt::ForRange { name, start, end, inclusive, body } => {
+                let (start, end) = self.range_bounds(name, start, end)?;
+                let mut i = start;
+                while i < end || (*inclusive && i == end) {
+                    // Each iteration gets its own binding, so closures keep the value they saw.
+                    let mut scope = Environment::enclose(self.environment.clone());
+                    scope.define(name.get_lexeme().to_string(), Value::Number(i));
+                    match self.execute_block(std::slice::from_ref(body), Rc::new(RefCell::new(scope))) {
+                        Err(ControlFlow::Break(_)) => break,
+                        Err(ControlFlow::Continue) => {},
+                        result => result?,
+                    }
+                    i += 1.0;
                 }
             }
         }
+        Ok(())
     }
 
-    fn stringify(&self, value: Value) -> String {
-        match value {
-            Value::Number(number) => number.to_string(),
-            Value::Boolean(boolean) => boolean.to_string(),
-            Value::String(string) => string,
-            Value::Nil => "nil".to_string(),
+    fn range_bounds(&mut self, name: &Token, start: &Expr, end: &Expr) -> Result<(f64, f64), ControlFlow> {
+        match (self.evaluate(start)?, self.evaluate(end)?) {
+            (Value::Number(start), Value::Number(end)) => Ok((start, end)),
+            (start, end) => Err(Error::runtime(format!("Range bounds must be numbers, got {} and {}",
+                                                       start.type_name(), end.type_name()), name).into()),
         }
     }
 
@@ -104,76 +397,305 @@ impl Interpreter {
         }
     }
 
-    fn evaluate(&mut self, expr: &Expr) -> Result<Value, String> {
-        match expr {
+    fn binary(&self, operator: &Token, left: Value, right: Value) -> Result<Value, Error> {
+        match (operator.get_type(), &left, &right) {
+            (TokenType::EQUAL_EQUAL, _, _) => return Ok(Value::Boolean(left == right)),
+            (TokenType::BANG_EQUAL, _, _) => return Ok(Value::Boolean(left != right)),
+            (TokenType::PLUS, Value::String(left), Value::String(right)) => {
+                let mut joined = String::with_capacity(left.len() + right.len());
+                joined.push_str(left);
+                joined.push_str(right);
+                return Ok(Value::String(joined.into()));
+            }
+            (t, Value::String(left), Value::String(right)) => {
+                if let Some(op) = string_operator(t) {
+                    return Ok(Value::Boolean(op(left, right)));
+                }
+            }
+            (TokenType::SLASH, Value::Number(_) | Value::BigInt(_), Value::Number(right)) if *right == 0.0 => {
+                return Err(Error::runtime("Division by zero", operator));
+            }
+            (t, Value::Number(left), Value::Number(right)) => {
+                if self.promote && let Some(value) = promoted_operation(t, *left, *right) {
+                    return Ok(value);
+                }
+                if let Some(op) = numeric_operator(t) {
+                    return Ok(op(*left, *right));
+                }
+            }
+            (t, Value::BigInt(_), Value::Number(_) | Value::BigInt(_)) | (t, Value::Number(_), Value::BigInt(_)) => {
+                if let Some(value) = big_operation(t, &left, &right) {
+                    return Ok(value);
+                }
+            }
+            _ => {}
+        }
+        Err(Error::runtime(format!("Unsupported operand types for '{}': {} and {}",
+                                   operator.get_lexeme(), left.type_name(), right.type_name()), operator))
+    }
+
+    /// Evaluates `expr`. Besides errors, a `return` inside a `loop`
+    /// expression unwinds through here to the enclosing function call.
+    fn evaluate(&mut self, expr: &Expr) -> Result<Value, ControlFlow> {
+        let value = match expr {
             Expr::Literal(value) => Ok(value.clone()),
             Expr::Unary { operator, right} => {
                 let right = self.evaluate(right)?;
                 match operator.get_type() {
                     TokenType::MINUS => match right {
                         Value::Number(value) => Ok(Value::Number(-value)),
-                        _ => Err(format!("Not a number: {:?}", operator)),
+                        Value::BigInt(value) => Ok(Value::BigInt(Rc::new(-value.as_ref()))),
+                        _ => Err(Error::runtime(format!("Operand of '-' must be a number, got {}", right.type_name()), operator)),
+                    },
+                    TokenType::PLUS => match right {
+                        Value::Number(_) | Value::BigInt(_) => Ok(right),
+                        _ => Err(Error::runtime(format!("Operand of '+' must be a number, got {}", right.type_name()), operator)),
                     },
                     TokenType::BANG => Ok(Value::Boolean(!self.is_truthy(&right))),
-                    _ => Err(format!("Unknown unary operator: {:?}", operator)),
+                    _ => Err(Error::runtime(format!("Unknown unary operator '{}'", operator.get_lexeme()), operator)),
                 }
             }
             Expr::Binary { operator, left, right } => {
-                let left = self.evaluate(&left)?;
-                let right = self.evaluate(&right)?;
-
-                match operator.get_type() {
-                    TokenType::PLUS => match (left, right) {
-                        (Value::Number(left), Value::Number(right)) => Ok(Value::Number(left + right)),
-                        (Value::String(left), Value::String(right)) => Ok(Value::String(left + &right)),
-                        _ => Err(format!("Error {:?} not supported or mismatching types.", operator)),
-                    },
-                    TokenType::MINUS => match (left, right) {
-                        (Value::Number(left), Value::Number(right)) => Ok(Value::Number(left - right)),
-                        _ => Err(format!("Not a number or non-numeric values for operator: {:?}", operator)),
-                    },
-                    TokenType::STAR => match (left, right) {
-                        (Value::Number(left), Value::Number(right)) => Ok(Value::Number(left * right)),
-                        _ => Err(format!("Error {:?} not supported or mismatching types.", operator)),
-                    },
-                    TokenType::SLASH => match (left, right) {
-                        (Value::Number(left), Value::Number(right)) => {
-                            if right == 0.0 {
-                                Err("Division by zero not allowed.".to_string())
-                            } else {
-                                Ok(Value::Number(left / right))
-                            }
-                        }
-                        _ => Err(format!("Error {:?} not supported or types not numeric.", operator)),
-                    }
-                    TokenType::EQUAL_EQUAL => Ok(Value::Boolean(left == right)),
-                    TokenType::BANG_EQUAL => Ok(Value::Boolean(left != right)),
-                    TokenType::GREATER => match (left, right) {
-                        (Value::Number(left), Value::Number(right)) => Ok(Value::Boolean(left > right)),
-                        _ => Err(format!("Error {:?} not supported or mismatching types.", operator)),
-                    },
-                    TokenType::GREATER_EQUAL => match (left, right) {
-                        (Value::Number(left), Value::Number(right)) => Ok(Value::Boolean(left >= right)),
-                        _ => Err(format!("Error {:?} not supported or mismatching types.", operator)),
-                    },
-                    TokenType::LESS => match (left, right) {
-                        (Value::Number(left), Value::Number(right)) => Ok(Value::Boolean(left < right)),
-                        _ => Err(format!("Error {:?} not supported or mismatching types.", operator)),
-                    },
-                    TokenType::LESS_EQUAL => match (left, right) {
-                        (Value::Number(left), Value::Number(right)) => Ok(Value::Boolean(left <= right)),
-                        _ => Err(format!("Error {:?} not supported or mismatching types.", operator)),
-                    },
-                    _ => Err(format!("Error {:?} unknown binary operator.", operator)),
+                let left = self.evaluate(left)?;
+                let right = self.evaluate(right)?;
+                self.binary(operator, left, right)
+            },
+            Expr::Logical { left, operator, right } => {
+                // The result is the deciding operand itself, not a coerced boolean.
+                let left = self.evaluate(left)?;
+                let short_circuits = if operator.get_type() == TokenType::OR {
+                    self.is_truthy(&left)
+                } else {
+                    !self.is_truthy(&left)
+                };
+                if short_circuits {
+                    Ok(left)
+                } else {
+                    Ok(self.evaluate(right)?)
+                }
+            },
+            Expr::Call { callee, paren, arguments } => {
+                let callee = self.evaluate(callee)?;
+                let mut values = Vec::new();
+                for argument in arguments {
+                    values.push(self.evaluate(argument)?);
+                }
+                self.call(&callee, values, Some(paren))
+            },
+            Expr::Get { object, name } => match self.evaluate(object)? {
+                Value::Instance(instance) => instance.get(name, self),
+                Value::Nil if self.lenient_nil => Ok(Value::Nil),
+                _ => Err(Error::runtime("Only instances have properties", name)),
+            },
+            Expr::Set { object, name, value } => {
+                let Value::Instance(instance) = self.evaluate(object)? else {
+                    return Err(Error::runtime("Only instances have fields", name).into());
+                };
+                let value = self.evaluate(value)?;
+                instance.set(name, value.clone());
+                Ok(value)
+            },
+            Expr::ListLiteral(elements) => {
+                let mut values = Vec::with_capacity(elements.len());
+                for element in elements {
+                    values.push(self.evaluate(element)?);
                 }
+                Ok(Value::List(Rc::new(RefCell::new(values))))
             },
-            Expr::Grouping(expr) => self.evaluate(expr),
-            Expr::Variable(name) => self.environment.borrow().get(name),
-            Expr::Assign { name, value } => {
+            Expr::Index { object, bracket, index } => match self.evaluate(object)? {
+                Value::Nil if self.lenient_nil => {
+                    self.evaluate(index)?;
+                    Ok(Value::Nil)
+                },
+                object => {
+                    let list = list(object, bracket)?;
+                    let index = self.evaluate(index)?;
+                    let list = list.borrow();
+                    let i = list_index(&index, list.len(), bracket)?;
+                    Ok(list[i].clone())
+                },
+            },
+            Expr::IndexSet { object, bracket, index, value } => {
+                let list = list(self.evaluate(object)?, bracket)?;
+                let index = self.evaluate(index)?;
+                let value = self.evaluate(value)?;
+                if self.frozen.contains_key(&Rc::as_ptr(&list)) {
+                    return Err(Error::runtime("Can't modify a frozen list", bracket).into());
+                }
+                let mut list = list.borrow_mut();
+                let i = list_index(&index, list.len(), bracket)?;
+                list[i] = value.clone();
+                Ok(value)
+            },
+            Expr::This(keyword) => self.environment.borrow().get(keyword),
+            Expr::Ternary { condition, then_branch, else_branch } => {
+                let condition = self.evaluate(condition)?;
+                if self.is_truthy(&condition) {
+                    Ok(self.evaluate(then_branch)?)
+                } else {
+                    Ok(self.evaluate(else_branch)?)
+                }
+            },
+            Expr::Loop(body) => loop {
+                match self.execute(body) {
+                    Ok(()) | Err(ControlFlow::Continue) => {},
+                    Err(ControlFlow::Break(value)) => break Ok(value.unwrap_or(Value::Nil)),
+                    Err(flow) => return Err(flow),
+                }
+            },
+            Expr::Grouping(expr) => Ok(self.evaluate(expr)?),
+            Expr::Variable { name, depth } => match depth.get() {
+                Some(depth) => self.environment.borrow().get_at(depth, name),
+                None => self.globals.borrow().get(name),
+            },
+            Expr::Assign { name, value, depth } => {
+                if let Some(value) = self.append_in_place(name, depth.get(), value)? {
+                    return Ok(value);
+                }
                 let value = self.evaluate(value)?;
-                self.environment.borrow_mut().assign(name, value.clone())?;
+                self.assign(name, depth.get(), value.clone())?;
                 Ok(value)
             }
+        };
+        value.map_err(ControlFlow::Error)
+    }
+
+    /// Stores a value in the variable a resolved `depth` refers to, or in the
+    /// global scope if it is unresolved.
+    fn assign(&mut self, name: &Token, depth: Option<usize>, value: Value) -> Result<(), Error> {
+        match depth {
+            Some(depth) => self.environment.borrow_mut().assign_at(depth, name, value)?,
+            None => self.globals.borrow_mut().assign(name, value)?,
+        };
+        Ok(())
+    }
+
+    /// Runs `name = name + right`, the form `+=` also takes, so that building
+    /// a string in a loop is linear: the variable lets go of its string
+    /// before the right side is appended, so unless another value shares it
+    /// the string grows in place. Returns `None`, having evaluated nothing,
+    /// for any other assignment.
+    fn append_in_place(&mut self, name: &Token, depth: Option<usize>, value: &Expr) -> Result<Option<Value>, ControlFlow> {
+        let Expr::Binary { operator, left, right } = value else {
+            return Ok(None);
+        };
+        let Expr::Variable { name: variable, depth: variable_depth } = left.as_ref() else {
+            return Ok(None);
+        };
+        if operator.get_type() != TokenType::PLUS || variable.get_lexeme() != name.get_lexeme() || variable_depth.get() != depth {
+            return Ok(None);
         }
+        let left = self.evaluate(left)?;
+        let right = self.evaluate(right)?;
+        let value = match (left, right) {
+            (Value::String(mut string), Value::String(suffix)) => {
+                self.assign(name, depth, Value::Nil)?;
+                Rc::make_mut(&mut string).push_str(&suffix);
+                Value::String(string)
+            },
+            (left, right) => self.binary(operator, left, right)?,
+        };
+        self.assign(name, depth, value.clone())?;
+        Ok(Some(value))
+    }
+}
+
+/// The object of an index expression, which must be a list.
+fn list(object: Value, bracket: &Token) -> Result<Rc<RefCell<Vec<Value>>>, Error> {
+    match object {
+        Value::List(list) => Ok(list),
+        other => Err(Error::runtime(format!("Only lists can be indexed, got {}", other.type_name()), bracket)),
+    }
+}
+
+/// Checks that `index` is a whole number within a list of `len` elements.
+fn list_index(index: &Value, len: usize, bracket: &Token) -> Result<usize, Error> {
+    let Value::Number(number) = index else {
+        return Err(Error::runtime(format!("List index must be a number, got {}", index.type_name()), bracket));
+    };
+    if number.fract() != 0.0 {
+        return Err(Error::runtime(format!("List index must be an integer, got {}", index), bracket));
+    }
+    if *number < 0.0 || *number >= len as f64 {
+        return Err(Error::runtime(format!("List index {} is out of bounds for a list of length {}", index, len), bracket));
+    }
+    Ok(*number as usize)
+}
+
+/// Binary operators defined on two numbers, keyed by token type.
+fn numeric_operator(t: TokenType) -> Option<fn(f64, f64) -> Value> {
+    let op: fn(f64, f64) -> Value = match t {
+        TokenType::PLUS => |a, b| Value::Number(a + b),
+        TokenType::MINUS => |a, b| Value::Number(a - b),
+        TokenType::STAR => |a, b| Value::Number(a * b),
+        TokenType::SLASH => |a, b| Value::Number(a / b),
+        TokenType::GREATER => |a, b| Value::Boolean(a > b),
+        TokenType::GREATER_EQUAL => |a, b| Value::Boolean(a >= b),
+        TokenType::LESS => |a, b| Value::Boolean(a < b),
+        TokenType::LESS_EQUAL => |a, b| Value::Boolean(a <= b),
+        _ => return None,
+    };
+    Some(op)
+}
+
+/// Comparisons defined on two strings: lexicographic, by Unicode code point.
+fn string_operator(t: TokenType) -> Option<fn(&str, &str) -> bool> {
+    let op: fn(&str, &str) -> bool = match t {
+        TokenType::GREATER => |a, b| a > b,
+        TokenType::GREATER_EQUAL => |a, b| a >= b,
+        TokenType::LESS => |a, b| a < b,
+        TokenType::LESS_EQUAL => |a, b| a <= b,
+        _ => return None,
+    };
+    Some(op)
+}
+
+/// Exact `+`, `-` and `*` on two integers whose `f64` result would be rounded.
+fn promoted_operation(t: TokenType, left: f64, right: f64) -> Option<Value> {
+    if left.fract() != 0.0 || right.fract() != 0.0 {
+        return None;
+    }
+    let result = numeric_operator(t)?(left, right);
+    if !matches!(result, Value::Number(number) if !bigint::is_exact_integer(number)) {
+        return None;
+    }
+    big_operation(t, &Value::Number(left), &Value::Number(right))
+}
+
+/// Arithmetic and comparison where at least one side needs a big integer.
+/// A fractional operand or a division falls back to `f64`.
+fn big_operation(t: TokenType, left: &Value, right: &Value) -> Option<Value> {
+    let (Some(big_left), Some(big_right)) = (as_big(left), as_big(right)) else {
+        return Some(numeric_operator(t)?(as_f64(left)?, as_f64(right)?));
+    };
+    let result = match t {
+        TokenType::PLUS => &big_left + &big_right,
+        TokenType::MINUS => &big_left - &big_right,
+        TokenType::STAR => &big_left * &big_right,
+        TokenType::GREATER => return Some(Value::Boolean(big_left > big_right)),
+        TokenType::GREATER_EQUAL => return Some(Value::Boolean(big_left >= big_right)),
+        TokenType::LESS => return Some(Value::Boolean(big_left < big_right)),
+        TokenType::LESS_EQUAL => return Some(Value::Boolean(big_left <= big_right)),
+        _ => return Some(numeric_operator(t)?(big_left.to_f64(), big_right.to_f64())),
+    };
+    Some(match result.to_exact_f64() {
+        Some(number) => Value::Number(number),
+        None => Value::BigInt(Rc::new(result)),
+    })
+}
+
+fn as_big(value: &Value) -> Option<BigInt> {
+    match value {
+        Value::BigInt(number) => Some(number.as_ref().clone()),
+        Value::Number(number) if number.fract() == 0.0 => Some(BigInt::from_f64(*number)),
+        _ => None,
+    }
+}
+
+fn as_f64(value: &Value) -> Option<f64> {
+    match value {
+        Value::BigInt(number) => Some(number.to_f64()),
+        Value::Number(number) => Some(*number),
+        _ => None,
     }
 }
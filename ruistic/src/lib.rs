@@ -0,0 +1,85 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+//! The Ruistic interpreter as a library, for embedding and for tests. The
+//! `ruistic` binary is a command-line wrapper around it.
+
+mod token;
+mod scanner;
+mod parser;
+mod resolver;
+mod expression;
+mod interpreter;
+mod statement;
+mod environment;
+mod function;
+mod native;
+mod class;
+mod error;
+mod bigint;
+mod history;
+
+use std::cell::RefCell;
+use std::io::Write;
+use std::rc::Rc;
+
+pub use crate::error::Error;
+pub use crate::expression::Expr;
+pub use crate::history::History;
+pub use crate::interpreter::{Interpreter, Output};
+pub use crate::native::{Native, NativeFunction};
+pub use crate::parser::Parser;
+pub use crate::resolver::Resolver;
+pub use crate::scanner::Scanner;
+pub use crate::statement::Stmt;
+pub use crate::token::{Token, TokenType, Value};
+
+/// Scans, parses, resolves and runs `src` in a fresh interpreter. A phase
+/// with errors stops the pipeline and all of its errors are returned; a
+/// runtime error stops the program and is returned alone.
+pub fn run(src: &str) -> Result<(), Vec<Error>> {
+    run_in(&mut Interpreter::new(), src)
+}
+
+/// What `try_run` captured from a program.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunResult {
+    /// Everything the program printed.
+    pub stdout: String,
+    /// Every error, reported as the command line would report it.
+    pub stderr: String,
+    pub errors: Vec<Error>,
+}
+
+/// Like `run`, but captures the program's output and error reports in
+/// memory instead of writing them to stdout and stderr.
+pub fn try_run(src: &str) -> RunResult {
+    let stdout = Rc::new(RefCell::new(Vec::new()));
+    let stderr = Rc::new(RefCell::new(Vec::new()));
+    let mut interpreter = Interpreter::with_outputs(stdout.clone(), stderr.clone());
+    let errors = run_in(&mut interpreter, src).err().unwrap_or_default();
+    for error in &errors {
+        // Writing to a `Vec` can't fail.
+        let _ = writeln!(stderr.borrow_mut(), "{}", error.report());
+    }
+    RunResult {
+        stdout: String::from_utf8_lossy(&stdout.borrow()).into_owned(),
+        stderr: String::from_utf8_lossy(&stderr.borrow()).into_owned(),
+        errors,
+    }
+}
+
+/// Serializes `statements` to JSON, each node tagged with its variant name
+/// and each token with its type, lexeme, literal, line and column.
+#[cfg(feature = "serde")]
+pub fn ast_to_json(statements: &[Stmt]) -> String {
+    serde_json::to_string(statements).expect("a syntax tree holds only serializable literals")
+}
+
+fn run_in(interpreter: &mut Interpreter, src: &str) -> Result<(), Vec<Error>> {
+    let tokens = Scanner::new(src.to_string()).try_scan_tokens()?;
+    let statements = Parser::new(tokens).try_parse()?;
+    Resolver::new().resolve(&statements)?;
+    interpreter.try_interpret(statements).map_err(|error| vec![error])
+}
@@ -2,75 +2,413 @@
 // This file is part of Ruistic, a custom programming language interpreter.
 // See LICENSE file for license information.
 
-mod token;
-mod scanner;
-mod parser;
-mod expression;
-mod interpreter;
-mod statement;
-mod environment;
+mod bench;
 
 use std::env;
 use std::io;
 use std::io::{Read, Write};
 use std::fs::File;
-use crate::interpreter::Interpreter;
-use crate::parser::Parser;
-use crate::scanner::Scanner;
+use std::process;
+use ruistic::{Error, History, Interpreter, Parser, Resolver, Scanner, Stmt, Token};
 
-fn run_file(path: &str) {
+/// Exit status of a script that fails to scan, parse or resolve, following
+/// the sysexits convention (`EX_DATAERR`).
+const EXIT_SYNTAX_ERROR: i32 = 65;
+/// Exit status when the script can't be read (`EX_NOINPUT`).
+const EXIT_NO_INPUT: i32 = 66;
+/// Exit status of a script that fails at runtime (`EX_SOFTWARE`).
+const EXIT_RUNTIME_ERROR: i32 = 70;
+
+/// Shown instead of the prompt while a statement spans several lines.
+const CONTINUATION_PROMPT: &str = "... ";
+
+/// Language modes selected on the command line.
+#[derive(Debug, Default, Clone, Copy)]
+struct Options {
+    implicit_return: bool,
+    curry: bool,
+    lenient_nil: bool,
+    promote: bool,
+    max_tokens: Option<usize>,
+    profile: bool,
+    /// Print the script's tokens instead of running it.
+    tokens: bool,
+    /// Print the script's syntax tree instead of running it.
+    dump_ast: bool,
+    /// Print how each variable reference resolves instead of running the script.
+    dump_resolved: bool,
+    /// Print the script's syntax tree as JSON instead of running it.
+    #[cfg(feature = "serde")]
+    emit_json: bool,
+}
+
+/// Runs a script, reporting its errors, and returns the exit status. Fails
+/// only if the script can't be read. The path `-` reads the script from
+/// stdin.
+fn run_file(path: &str, options: Options) -> io::Result<i32> {
     let mut contents = String::new();
-    let mut file = File::open(path).unwrap();
-    file.read_to_string(&mut contents).unwrap();
-    run(&contents).unwrap();
+    if path == "-" {
+        io::stdin().read_to_string(&mut contents)?;
+    } else {
+        File::open(path)?.read_to_string(&mut contents)?;
+    }
+    if options.tokens {
+        return Ok(dump_tokens(&contents, options));
+    }
+    if options.dump_ast {
+        return Ok(dump_ast(&contents, options));
+    }
+    if options.dump_resolved {
+        return Ok(dump_resolved(&contents, options));
+    }
+    #[cfg(feature = "serde")]
+    if options.emit_json {
+        return Ok(emit_json(&contents, options));
+    }
+    Ok(match run(&contents, options) {
+        Ok(()) => 0,
+        Err(errors) => {
+            report(&errors);
+            // Each phase stops the script, so all its errors are of one kind.
+            match errors.first() {
+                Some(Error::Runtime { .. }) => EXIT_RUNTIME_ERROR,
+                _ => EXIT_SYNTAX_ERROR,
+            }
+        },
+    })
 }
 
-fn run_prompt() {
-    let mut interpreter = Interpreter::new();
+/// Prints each token of `src` on its own line, and returns the exit status.
+fn dump_tokens(src: &str, options: Options) -> i32 {
+    match scan(src, options) {
+        Ok(tokens) => {
+            for token in tokens {
+                println!("{}", token);
+            }
+            0
+        },
+        Err(errors) => {
+            report(&errors);
+            EXIT_SYNTAX_ERROR
+        },
+    }
+}
+
+/// Prints each top-level statement of `src` as an S-expression on its own
+/// line, and returns the exit status.
+fn dump_ast(src: &str, options: Options) -> i32 {
+    match parse_only(src, options) {
+        Ok(statements) => {
+            for statement in statements {
+                println!("{}", statement);
+            }
+            0
+        },
+        Err(errors) => {
+            report(&errors);
+            EXIT_SYNTAX_ERROR
+        },
+    }
+}
+
+/// Prints the resolver's report of each variable reference in `src`, one
+/// per line, and returns the exit status.
+fn dump_resolved(src: &str, options: Options) -> i32 {
+    let result = parse_only(src, options).and_then(|statements| {
+        let mut resolver = Resolver::new().with_report();
+        resolver.resolve(&statements)?;
+        Ok(resolver.report().to_vec())
+    });
+    match result {
+        Ok(report) => {
+            for line in report {
+                println!("{}", line);
+            }
+            0
+        },
+        Err(errors) => {
+            report(&errors);
+            EXIT_SYNTAX_ERROR
+        },
+    }
+}
+
+/// Prints the syntax tree of `src` as JSON, and returns the exit status.
+#[cfg(feature = "serde")]
+fn emit_json(src: &str, options: Options) -> i32 {
+    match parse_only(src, options) {
+        Ok(statements) => {
+            println!("{}", ruistic::ast_to_json(&statements));
+            0
+        },
+        Err(errors) => {
+            report(&errors);
+            EXIT_SYNTAX_ERROR
+        },
+    }
+}
+
+/// Scans and parses `src` without resolving it, for the dumps of its syntax.
+fn parse_only(src: &str, options: Options) -> Result<Vec<Stmt>, Vec<Error>> {
+    let tokens = scan(src, options)?;
+    let mut parser = Parser::new(tokens);
+    parser.set_implicit_return(options.implicit_return);
+    parser.try_parse()
+}
+
+fn report(errors: &[Error]) {
+    for error in errors {
+        eprintln!("{}", error.report());
+    }
+}
+
+fn run_prompt(options: Options) {
+    let mut interpreter = new_interpreter(options);
+    let mut history = History::new();
+    let prompt = env::var("RUISTIC_PROMPT").unwrap_or_else(|_| "> ".to_string());
+
+    loop {
+        print!("{}", prompt);
+        io::stdout().flush().unwrap();
+
+        let Some(line) = read_line() else {
+            break;
+        };
+        let mut input = line.trim().to_string();
+        if input == "quit" || input == "exit" {
+            break;
+        }
+        if input == ":history" {
+            print!("{}", history);
+            continue;
+        }
+        // An empty continuation line gives up and runs what was typed, so an
+        // unfinished statement reports its error instead of waiting forever.
+        while is_incomplete(&input, options) {
+            print!("{}", CONTINUATION_PROMPT);
+            io::stdout().flush().unwrap();
+            match read_line() {
+                Some(line) if !line.trim().is_empty() => {
+                    input.push('\n');
+                    input.push_str(line.trim_end());
+                },
+                _ => break,
+            }
+        }
+        history.push(&input);
+        if let Err(errors) = run_line(&input, &mut interpreter, options) {
+            report(&errors);
+        }
+    }
+}
+
+/// Reads a line from stdin, or `None` at the end of the input.
+fn read_line() -> Option<String> {
+    let mut line = String::new();
+    match io::stdin().read_line(&mut line) {
+        Ok(0) | Err(_) => None,
+        Ok(_) => Some(line),
+    }
+}
 
+/// Whether `src` stops partway through a statement, such as an unclosed
+/// block or a `var` missing its value: every parse error it has is at the
+/// end of the input, so more lines could complete it.
+fn is_incomplete(src: &str, options: Options) -> bool {
+    let Ok(tokens) = scanner(src, options).try_scan_tokens() else {
+        return false;
+    };
+    let Some(end) = tokens.last() else {
+        return false;
+    };
+    let end = (end.get_line(), end.get_column());
+    if Parser::new(tokens.clone()).parse_expression().is_ok() {
+        return false;
+    }
+    let mut parser = Parser::new(tokens);
+    parser.set_implicit_return(options.implicit_return);
+    match parser.try_parse() {
+        Ok(_) => false,
+        Err(errors) => errors.iter().all(|error| match error {
+            Error::Parse { line, column, .. } => (*line, *column) == end,
+            _ => false,
+        }),
+    }
+}
+
+/// Reads one expression per line and prints its value.
+fn run_calculator(options: Options) {
+    let mut interpreter = new_interpreter(options);
     loop {
-        print!("> ");
+        print!("= ");
         io::stdout().flush().unwrap();
 
         let mut input = String::new();
-        io::stdin().read_line(&mut input).unwrap();
+        if io::stdin().read_line(&mut input).unwrap() == 0 {
+            break;
+        }
         let input = input.trim();
+        if input.is_empty() {
+            continue;
+        }
         if input == "quit" || input == "exit" {
             break;
         }
-        if let Err(err) = run_line(input, &mut interpreter) {
-            eprintln!("Error: {}", err);
+        let tokens = scanner(input, options).scan_tokens();
+        let result = Parser::new(tokens)
+            .parse_expression()
+            .map_err(|err| vec![err])
+            .and_then(|expr| {
+                Resolver::new().resolve_expression(&expr)?;
+                interpreter.eval_expression(&expr).map_err(|err| vec![err])
+            });
+        match result {
+            Ok(value) => println!("{}", value),
+            Err(errors) => errors.iter().for_each(|err| eprintln!("Error: {}", err)),
         }
     }
 }
 
-fn run_line(src: &str, interpreter: &mut Interpreter) -> Result<(), String> {
+fn scanner(src: &str, options: Options) -> Scanner {
     let scanner = Scanner::new(src.to_string());
-    let tokens = scanner.scan_tokens();
-    let mut parser = Parser::new(tokens);
-    let statements = parser.parse();
-
-    interpreter.interpret(statements);
-    Ok(())
+    match options.max_tokens {
+        Some(limit) => scanner.with_max_tokens(limit),
+        None => scanner,
+    }
 }
 
+/// Scans `src`, printing any warnings to stderr.
+fn scan(src: &str, options: Options) -> Result<Vec<Token>, Vec<Error>> {
+    let mut scanner = scanner(src, options);
+    let tokens = scanner.try_scan_tokens();
+    for warning in scanner.warnings() {
+        eprintln!("Warning: {}", warning);
+    }
+    tokens
+}
 
-fn run(src: &str) -> Result<(), String> {
-    let scanner = Scanner::new(src.to_string());
-    let tokens = scanner.scan_tokens();
+/// Parses and resolves `tokens`, returning every error of the first phase
+/// that fails.
+fn parse(tokens: Vec<Token>, options: Options) -> Result<Vec<Stmt>, Vec<Error>> {
     let mut parser = Parser::new(tokens);
-    let statements = parser.parse();
+    parser.set_implicit_return(options.implicit_return);
+    let statements = parser.try_parse()?;
+    Resolver::new().resolve(&statements)?;
+    Ok(statements)
+}
+
+fn new_interpreter(options: Options) -> Interpreter {
     let mut interpreter = Interpreter::new();
-    interpreter.interpret(statements);
-    Ok(())
+    interpreter.set_curry(options.curry);
+    interpreter.set_lenient_nil(options.lenient_nil);
+    interpreter.set_promote(options.promote);
+    interpreter
+}
+
+/// Runs one entry of a REPL session. Nothing of an entry with scan, parse
+/// or resolution errors runs, so a mistyped line leaves the session's
+/// variables as they were.
+fn run_line(src: &str, interpreter: &mut Interpreter, options: Options) -> Result<(), Vec<Error>> {
+    let tokens = scan(src, options)?;
+    // A bare expression, with no `;` making it a statement, echoes its value.
+    if let Ok(expr) = Parser::new(tokens.clone()).parse_expression() {
+        Resolver::new().resolve_expression(&expr)?;
+        let value = interpreter.eval_expression(&expr).map_err(|error| vec![error])?;
+        println!("{}", value.repr());
+        return Ok(());
+    }
+    interpreter.try_interpret(parse(tokens, options)?).map_err(|error| vec![error])
+}
+
+/// Like `ruistic::run`, but with the language modes in `options`. Nothing
+/// runs if the script has scan, parse or resolution errors.
+fn run(src: &str, options: Options) -> Result<(), Vec<Error>> {
+    let tokens = scan(src, options)?;
+    let statements = parse(tokens, options)?;
+    let mut interpreter = new_interpreter(options);
+    interpreter.set_profile(options.profile);
+    let result = interpreter.try_interpret(statements);
+    if options.profile {
+        eprint!("{}", format_profile(&interpreter.line_counts()));
+    }
+    result.map_err(|error| vec![error])
+}
+
+fn format_profile(counts: &[(usize, usize)]) -> String {
+    let mut report = format!("{:>6}  {:>10}\n", "line", "executions");
+    for (line, count) in counts {
+        report.push_str(&format!("{:>6}  {:>10}\n", line, count));
+    }
+    report
 }
 
 fn main() {
-    if env::args().len() > 2 {
-        eprintln!("Usage: {} [script]", env::args().next().unwrap());
-    } else if env::args().len() == 2 {
-        run_file(&env::args().nth(1).unwrap());
+    let mut args: Vec<String> = env::args().collect();
+    let program = args.remove(0);
+    let mut options = Options::default();
+    args.retain(|arg| match arg.as_str() {
+        "--implicit-return" => {
+            options.implicit_return = true;
+            false
+        },
+        "--curry" => {
+            options.curry = true;
+            false
+        },
+        "--lenient-nil" => {
+            options.lenient_nil = true;
+            false
+        },
+        "--promote" => {
+            options.promote = true;
+            false
+        },
+        "--profile" => {
+            options.profile = true;
+            false
+        },
+        "--tokens" => {
+            options.tokens = true;
+            false
+        },
+        "--dump-ast" => {
+            options.dump_ast = true;
+            false
+        },
+        "--dump-resolved" => {
+            options.dump_resolved = true;
+            false
+        },
+        #[cfg(feature = "serde")]
+        "--emit-json" => {
+            options.emit_json = true;
+            false
+        },
+        _ => match arg.strip_prefix("--max-tokens=").map(str::parse) {
+            Some(Ok(limit)) => {
+                options.max_tokens = Some(limit);
+                false
+            },
+            _ => true,
+        },
+    });
+
+    if args.len() > 1 {
+        eprintln!("Usage: {} [--implicit-return] [--curry] [--lenient-nil] [--promote] [--profile] [--tokens] [--dump-ast] [--dump-resolved] [--max-tokens=N] [--bench | --calc | script | -]", program);
+    } else if args.first().map(String::as_str) == Some("--bench") {
+        bench::run_benchmarks();
+    } else if args.first().map(String::as_str) == Some("--calc") {
+        run_calculator(options);
+    } else if let Some(path) = args.first() {
+        match run_file(path, options) {
+            Ok(0) => {},
+            Ok(status) => process::exit(status),
+            Err(error) => {
+                eprintln!("Could not open file '{}': {}", path, error);
+                process::exit(EXIT_NO_INPUT);
+            },
+        }
     } else {
-        run_prompt();
+        run_prompt(options);
     }
 }
@@ -0,0 +1,497 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+use std::cell::RefCell;
+use std::fmt;
+use std::mem;
+use std::rc::Rc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use crate::bigint::BigInt;
+use crate::interpreter::Interpreter;
+use crate::token::Value;
+
+/// The Rust side of a native function. It gets the interpreter, so it can
+/// call back into script values it is handed with `call_value`, and
+/// returns an error message, which the call site locates.
+pub type NativeFunction = fn(&mut Interpreter, &[Value]) -> Result<Value, String>;
+
+/// A function implemented in Rust and defined in the global scope.
+pub struct Native {
+    name: &'static str,
+    arity: usize,
+    /// How many of the last arguments may be left out.
+    optional: usize,
+    function: NativeFunction,
+}
+
+impl Native {
+    /// A native taking exactly `arity` arguments, for a host to give to
+    /// `Interpreter::define_native`.
+    pub fn new(name: &'static str, arity: usize, function: NativeFunction) -> Self {
+        Self { name, arity, optional: 0, function }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn arity(&self) -> usize {
+        self.arity
+    }
+
+    /// The fewest arguments the function takes.
+    pub fn min_arity(&self) -> usize {
+        self.arity - self.optional
+    }
+
+    /// Runs the function; the caller has already checked that the number of
+    /// arguments is from `min_arity` to `arity`.
+    pub fn call(&self, interpreter: &mut Interpreter, arguments: &[Value]) -> Result<Value, String> {
+        (self.function)(interpreter, arguments)
+    }
+}
+
+impl fmt::Debug for Native {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<native fn {}>", self.name)
+    }
+}
+
+/// Natives compare by identity, like script functions.
+impl PartialEq for Native {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+/// The most arguments a call can pass, which a variadic native takes as
+/// its arity.
+const MAX_ARGUMENTS: usize = 255;
+
+/// Every native function, as the interpreter defines them in a new global scope.
+pub fn natives() -> Vec<Native> {
+    vec![
+        Native { name: "clock", arity: 0, optional: 0, function: clock },
+        Native { name: "len", arity: 1, optional: 0, function: len },
+        Native { name: "sqrt", arity: 1, optional: 0, function: sqrt },
+        Native { name: "abs", arity: 1, optional: 0, function: abs },
+        Native { name: "floor", arity: 1, optional: 0, function: floor },
+        Native { name: "ceil", arity: 1, optional: 0, function: ceil },
+        Native { name: "pow", arity: 2, optional: 0, function: pow },
+        Native { name: "gcd", arity: 2, optional: 0, function: gcd },
+        Native { name: "lcm", arity: 2, optional: 0, function: lcm },
+        Native { name: "log", arity: 2, optional: 1, function: log },
+        Native { name: "approxEq", arity: 3, optional: 1, function: approx_eq },
+        Native { name: "split", arity: 2, optional: 0, function: split },
+        Native { name: "chars", arity: 1, optional: 0, function: chars },
+        Native { name: "codePoint", arity: 1, optional: 0, function: code_point },
+        Native { name: "fromCodePoint", arity: 1, optional: 0, function: from_code_point },
+        Native { name: "type", arity: 1, optional: 0, function: type_of },
+        Native { name: "toBoolean", arity: 1, optional: 0, function: to_boolean },
+        Native { name: "toInt", arity: 1, optional: 0, function: to_int },
+        Native { name: "toFloat", arity: 1, optional: 0, function: to_float },
+        Native { name: "first", arity: 1, optional: 0, function: first },
+        Native { name: "last", arity: 1, optional: 0, function: last },
+        Native { name: "count", arity: 2, optional: 0, function: count },
+        Native { name: "frequency", arity: 1, optional: 0, function: frequency },
+        Native { name: "sum", arity: 1, optional: 0, function: sum },
+        Native { name: "avg", arity: 1, optional: 0, function: avg },
+        Native { name: "minOf", arity: 1, optional: 0, function: min_of },
+        Native { name: "maxOf", arity: 1, optional: 0, function: max_of },
+        Native { name: "sizeof", arity: 1, optional: 0, function: sizeof },
+        Native { name: "freeze", arity: 1, optional: 0, function: freeze },
+        Native { name: "assertThrows", arity: 1, optional: 0, function: assert_throws },
+        Native { name: "printf", arity: MAX_ARGUMENTS, optional: MAX_ARGUMENTS - 1, function: printf },
+    ]
+}
+
+/// Seconds since the Unix epoch, with a fractional part, for timing scripts.
+fn clock(_: &mut Interpreter, _: &[Value]) -> Result<Value, String> {
+    let elapsed = SystemTime::now().duration_since(UNIX_EPOCH).map_err(|error| error.to_string())?;
+    Ok(Value::Number(elapsed.as_secs_f64()))
+}
+
+/// The number of characters in a string or elements in a list.
+fn len(_: &mut Interpreter, arguments: &[Value]) -> Result<Value, String> {
+    match &arguments[0] {
+        Value::String(string) => Ok(Value::Number(string.chars().count() as f64)),
+        Value::List(list) => Ok(Value::Number(list.borrow().len() as f64)),
+        other => Err(format!("len() expects a string or list, got {}", other.type_name())),
+    }
+}
+
+/// The parts of a string between occurrences of a separator; an empty
+/// separator splits the string into its characters.
+fn split(_: &mut Interpreter, arguments: &[Value]) -> Result<Value, String> {
+    let (Value::String(string), Value::String(separator)) = (&arguments[0], &arguments[1]) else {
+        return Err(format!("split() expects two strings, got {} and {}", arguments[0].type_name(), arguments[1].type_name()));
+    };
+    let parts: Vec<Value> = if separator.is_empty() {
+        string.chars().map(|c| Value::String(c.to_string().into())).collect()
+    } else {
+        string.split(separator.as_ref()).map(|part| Value::String(part.to_string().into())).collect()
+    };
+    Ok(Value::List(Rc::new(RefCell::new(parts))))
+}
+
+/// The argument of `name` as a string.
+fn string<'a>(name: &str, value: &'a Value) -> Result<&'a str, String> {
+    match value {
+        Value::String(string) => Ok(string),
+        other => Err(format!("{}() expects a string, got {}", name, other.type_name())),
+    }
+}
+
+/// A list of the characters of a string, each as a one-character string.
+fn chars(_: &mut Interpreter, arguments: &[Value]) -> Result<Value, String> {
+    let chars = string("chars", &arguments[0])?.chars().map(|c| Value::String(c.to_string().into())).collect();
+    Ok(Value::List(Rc::new(RefCell::new(chars))))
+}
+
+/// The Unicode scalar value of a one-character string.
+fn code_point(_: &mut Interpreter, arguments: &[Value]) -> Result<Value, String> {
+    let string = string("codePoint", &arguments[0])?;
+    let mut chars = string.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(Value::Number(c as u32 as f64)),
+        _ => Err(format!("codePoint() expects a one-character string, got {:?}", string)),
+    }
+}
+
+/// The one-character string whose Unicode scalar value is the argument.
+fn from_code_point(_: &mut Interpreter, arguments: &[Value]) -> Result<Value, String> {
+    let number = number("fromCodePoint", &arguments[0])?;
+    let c = (number.fract() == 0.0 && (0.0..=u32::MAX as f64).contains(&number))
+        .then(|| char::from_u32(number as u32))
+        .flatten()
+        .ok_or_else(|| format!("fromCodePoint() expects a Unicode scalar value, got {}", arguments[0]))?;
+    Ok(Value::String(c.to_string().into()))
+}
+
+/// The name of a value's type, as runtime errors spell it.
+fn type_of(_: &mut Interpreter, arguments: &[Value]) -> Result<Value, String> {
+    Ok(Value::String(arguments[0].type_name().to_string().into()))
+}
+
+/// Whether a value is truthy, as a condition would take it: only nil and
+/// false are not.
+fn to_boolean(_: &mut Interpreter, arguments: &[Value]) -> Result<Value, String> {
+    Ok(Value::Boolean(!matches!(arguments[0], Value::Nil | Value::Boolean(false))))
+}
+
+/// A number truncated toward zero, or a string of decimal digits with an
+/// optional sign parsed exactly; a string too large for a number gives a
+/// big integer.
+fn to_int(_: &mut Interpreter, arguments: &[Value]) -> Result<Value, String> {
+    match &arguments[0] {
+        Value::Number(number) if number.is_finite() => Ok(Value::Number(number.trunc())),
+        Value::BigInt(_) => Ok(arguments[0].clone()),
+        Value::String(string) => {
+            let text = string.trim();
+            let big = BigInt::parse(text.strip_prefix('+').unwrap_or(text))
+                .ok_or_else(|| format!("toInt() can't convert {:?} to an integer", string.as_ref()))?;
+            Ok(match big.to_exact_f64() {
+                Some(number) => Value::Number(number),
+                None => Value::BigInt(Rc::new(big)),
+            })
+        },
+        Value::Number(number) => Err(format!("toInt() can't convert {} to an integer", number)),
+        other => Err(format!("toInt() expects a number or string, got {}", other.type_name())),
+    }
+}
+
+/// A number unchanged, a big integer rounded, or a string parsed as a
+/// finite number.
+fn to_float(_: &mut Interpreter, arguments: &[Value]) -> Result<Value, String> {
+    match &arguments[0] {
+        Value::String(string) => string.trim().parse::<f64>().ok()
+            .filter(|number| number.is_finite())
+            .map(Value::Number)
+            .ok_or_else(|| format!("toFloat() can't convert {:?} to a number", string.as_ref())),
+        other => Ok(Value::Number(number("toFloat", other).map_err(|_| {
+            format!("toFloat() expects a number or string, got {}", other.type_name())
+        })?)),
+    }
+}
+
+/// The argument of `name` as an `f64`; a big integer is rounded.
+fn number(name: &str, value: &Value) -> Result<f64, String> {
+    match value {
+        Value::Number(number) => Ok(*number),
+        Value::BigInt(number) => Ok(number.to_f64()),
+        other => Err(format!("{}() expects a number, got {}", name, other.type_name())),
+    }
+}
+
+fn sqrt(_: &mut Interpreter, arguments: &[Value]) -> Result<Value, String> {
+    let number = number("sqrt", &arguments[0])?;
+    if number < 0.0 {
+        return Err(format!("sqrt() expects a non-negative number, got {}", arguments[0]));
+    }
+    Ok(Value::Number(number.sqrt()))
+}
+
+fn abs(_: &mut Interpreter, arguments: &[Value]) -> Result<Value, String> {
+    match &arguments[0] {
+        Value::BigInt(number) if number.is_negative() => Ok(Value::BigInt(Rc::new(-number.as_ref()))),
+        Value::BigInt(_) => Ok(arguments[0].clone()),
+        other => Ok(Value::Number(number("abs", other)?.abs())),
+    }
+}
+
+/// A big integer is already whole, so it is returned unchanged.
+fn floor(_: &mut Interpreter, arguments: &[Value]) -> Result<Value, String> {
+    match &arguments[0] {
+        Value::BigInt(_) => Ok(arguments[0].clone()),
+        other => Ok(Value::Number(number("floor", other)?.floor())),
+    }
+}
+
+fn ceil(_: &mut Interpreter, arguments: &[Value]) -> Result<Value, String> {
+    match &arguments[0] {
+        Value::BigInt(_) => Ok(arguments[0].clone()),
+        other => Ok(Value::Number(number("ceil", other)?.ceil())),
+    }
+}
+
+fn pow(_: &mut Interpreter, arguments: &[Value]) -> Result<Value, String> {
+    Ok(Value::Number(number("pow", &arguments[0])?.powf(number("pow", &arguments[1])?)))
+}
+
+/// The largest integer a number holds exactly, 2^53.
+const MAX_EXACT_INTEGER: f64 = 9007199254740992.0;
+
+/// The argument of `name` as the magnitude of a whole number that a number
+/// holds exactly.
+fn integer(name: &str, value: &Value) -> Result<u64, String> {
+    let number = number(name, value)?;
+    if number.fract() != 0.0 {
+        return Err(format!("{}() expects an integer, got {}", name, value));
+    }
+    if number.abs() > MAX_EXACT_INTEGER {
+        return Err(format!("{}() can't take an integer as large as {}", name, value));
+    }
+    Ok(number.abs() as u64)
+}
+
+fn greatest_common_divisor(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// The greatest common divisor of two integers, which is never negative;
+/// `gcd(0, 0)` is 0.
+fn gcd(_: &mut Interpreter, arguments: &[Value]) -> Result<Value, String> {
+    let (a, b) = (integer("gcd", &arguments[0])?, integer("gcd", &arguments[1])?);
+    Ok(Value::Number(greatest_common_divisor(a, b) as f64))
+}
+
+/// The least common multiple of two integers, which is never negative; it
+/// is 0 if either is.
+fn lcm(_: &mut Interpreter, arguments: &[Value]) -> Result<Value, String> {
+    let (a, b) = (integer("lcm", &arguments[0])?, integer("lcm", &arguments[1])?);
+    if a == 0 || b == 0 {
+        return Ok(Value::Number(0.0));
+    }
+    Ok(Value::Number((a / greatest_common_divisor(a, b)) as f64 * b as f64))
+}
+
+/// The logarithm of a positive number to a base, or the natural logarithm
+/// if the base is left out. Bases 2 and 10 give exact results for their
+/// powers.
+fn log(_: &mut Interpreter, arguments: &[Value]) -> Result<Value, String> {
+    let x = number("log", &arguments[0])?;
+    if x <= 0.0 {
+        return Err(format!("log() expects a positive number, got {}", arguments[0]));
+    }
+    let Some(base) = arguments.get(1) else {
+        return Ok(Value::Number(x.ln()));
+    };
+    Ok(Value::Number(match number("log", base)? {
+        2.0 => x.log2(),
+        10.0 => x.log10(),
+        base if base > 0.0 && base != 1.0 => x.ln() / base.ln(),
+        _ => return Err(format!("log() expects a positive base other than 1, got {}", arguments[1])),
+    }))
+}
+
+/// Tolerance of `approxEq` when no epsilon is given.
+const DEFAULT_EPSILON: f64 = 1e-9;
+
+/// Whether two numbers differ by at most an epsilon, `DEFAULT_EPSILON` if
+/// the third argument is left out.
+fn approx_eq(_: &mut Interpreter, arguments: &[Value]) -> Result<Value, String> {
+    let (a, b) = (number("approxEq", &arguments[0])?, number("approxEq", &arguments[1])?);
+    let epsilon = match arguments.get(2) {
+        Some(epsilon) => number("approxEq", epsilon)?,
+        None => DEFAULT_EPSILON,
+    };
+    if epsilon.is_nan() || epsilon < 0.0 {
+        return Err(format!("approxEq() expects a non-negative epsilon, got {}", arguments[2]));
+    }
+    Ok(Value::Boolean((a - b).abs() <= epsilon))
+}
+
+/// The argument of `name` as a list.
+fn list<'a>(name: &str, value: &'a Value) -> Result<&'a Rc<RefCell<Vec<Value>>>, String> {
+    match value {
+        Value::List(list) => Ok(list),
+        other => Err(format!("{}() expects a list, got {}", name, other.type_name())),
+    }
+}
+
+fn first(_: &mut Interpreter, arguments: &[Value]) -> Result<Value, String> {
+    list("first", &arguments[0])?.borrow().first().cloned().ok_or_else(|| "first() of an empty list".to_string())
+}
+
+fn last(_: &mut Interpreter, arguments: &[Value]) -> Result<Value, String> {
+    list("last", &arguments[0])?.borrow().last().cloned().ok_or_else(|| "last() of an empty list".to_string())
+}
+
+/// How many elements of a list `==` a value.
+fn count(_: &mut Interpreter, arguments: &[Value]) -> Result<Value, String> {
+    let list = list("count", &arguments[0])?.borrow();
+    Ok(Value::Number(list.iter().filter(|element| **element == arguments[1]).count() as f64))
+}
+
+/// A `[value, count]` pair for each distinct element of a list, in the order
+/// the values first appear. There is no map type, so the pairs are lists.
+fn frequency(_: &mut Interpreter, arguments: &[Value]) -> Result<Value, String> {
+    let mut counts: Vec<(Value, usize)> = Vec::new();
+    for element in list("frequency", &arguments[0])?.borrow().iter() {
+        match counts.iter_mut().find(|(value, _)| value == element) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((element.clone(), 1)),
+        }
+    }
+    let pairs = counts.into_iter()
+        .map(|(value, count)| Value::List(Rc::new(RefCell::new(vec![value, Value::Number(count as f64)]))))
+        .collect();
+    Ok(Value::List(Rc::new(RefCell::new(pairs))))
+}
+
+/// The elements of a non-empty list of numbers; a big integer is rounded.
+fn numbers(name: &str, value: &Value) -> Result<Vec<f64>, String> {
+    let list = list(name, value)?.borrow();
+    if list.is_empty() {
+        return Err(format!("{}() of an empty list", name));
+    }
+    list.iter()
+        .map(|element| number(name, element)
+            .map_err(|_| format!("{}() expects a list of numbers, found {}", name, element.type_name())))
+        .collect()
+}
+
+fn sum(_: &mut Interpreter, arguments: &[Value]) -> Result<Value, String> {
+    Ok(Value::Number(numbers("sum", &arguments[0])?.iter().sum()))
+}
+
+fn avg(_: &mut Interpreter, arguments: &[Value]) -> Result<Value, String> {
+    let numbers = numbers("avg", &arguments[0])?;
+    Ok(Value::Number(numbers.iter().sum::<f64>() / numbers.len() as f64))
+}
+
+fn min_of(_: &mut Interpreter, arguments: &[Value]) -> Result<Value, String> {
+    Ok(Value::Number(numbers("minOf", &arguments[0])?.into_iter().fold(f64::INFINITY, f64::min)))
+}
+
+fn max_of(_: &mut Interpreter, arguments: &[Value]) -> Result<Value, String> {
+    Ok(Value::Number(numbers("maxOf", &arguments[0])?.into_iter().fold(f64::NEG_INFINITY, f64::max)))
+}
+
+/// Roughly how many bytes a value takes: every value the same fixed size,
+/// plus the text of a string, the digits of a big integer, and the
+/// elements of a list, counted recursively.
+fn sizeof(_: &mut Interpreter, arguments: &[Value]) -> Result<Value, String> {
+    Ok(Value::Number(size_of_value(&arguments[0], &mut Vec::new()) as f64))
+}
+
+/// The size `sizeof` reports. A list already in `seen` adds only the value
+/// referring to it, so a list that contains itself is counted once.
+fn size_of_value(value: &Value, seen: &mut Vec<*const RefCell<Vec<Value>>>) -> usize {
+    let contents = match value {
+        Value::String(string) => string.len(),
+        Value::BigInt(number) => mem::size_of::<BigInt>() + number.heap_size(),
+        Value::List(list) if !seen.contains(&Rc::as_ptr(list)) => {
+            seen.push(Rc::as_ptr(list));
+            let elements: usize = list.borrow().iter().map(|element| size_of_value(element, seen)).sum();
+            mem::size_of::<RefCell<Vec<Value>>>() + elements
+        },
+        _ => 0,
+    };
+    mem::size_of::<Value>() + contents
+}
+
+/// Makes a list read-only, along with every list nested in it, and
+/// returns it. Other values in it, such as instances, stay mutable.
+fn freeze(interpreter: &mut Interpreter, arguments: &[Value]) -> Result<Value, String> {
+    interpreter.freeze(list("freeze", &arguments[0])?);
+    Ok(arguments[0].clone())
+}
+
+/// Calls a function with no arguments and fails unless the call raises a
+/// runtime error, which it swallows.
+fn assert_throws(interpreter: &mut Interpreter, arguments: &[Value]) -> Result<Value, String> {
+    let min_arity = match &arguments[0] {
+        Value::Function(function) => function.arity(),
+        Value::Native(native) => native.min_arity(),
+        Value::Class(class) => class.arity(),
+        other => return Err(format!("assertThrows() expects a function, got {}", other.type_name())),
+    };
+    if min_arity > 0 {
+        return Err(format!("assertThrows() expects a function of no arguments, got {}", arguments[0]));
+    }
+    match interpreter.call_value(&arguments[0], Vec::new()) {
+        Ok(value) => Err(format!("assertThrows() expected {} to raise an error, but it returned {}", arguments[0], value.repr())),
+        Err(_) => Ok(Value::Nil),
+    }
+}
+
+/// Writes a format string to the `print` output with no newline added,
+/// replacing `%d` with an integer, `%f` with a number to six decimal
+/// places, `%s` with any value as `print` shows it, and `%%` with `%`.
+/// Each specifier takes the next argument, and there must be exactly as
+/// many arguments as specifiers.
+fn printf(interpreter: &mut Interpreter, arguments: &[Value]) -> Result<Value, String> {
+    let format = string("printf", &arguments[0])?;
+    let mut values = arguments[1..].iter();
+    let mut text = String::new();
+    let mut chars = format.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            text.push(c);
+            continue;
+        }
+        let specifier = chars.next().ok_or_else(|| "printf() format ends with a lone '%'".to_string())?;
+        if specifier == '%' {
+            text.push('%');
+            continue;
+        }
+        let value = values.next().ok_or_else(|| format!(
+            "printf() format has more specifiers than the {} arguments given", arguments.len() - 1,
+        ))?;
+        match specifier {
+            'd' => match value {
+                Value::BigInt(number) => text.push_str(&number.to_string()),
+                other => {
+                    let number = number("printf", other)?;
+                    if !number.is_finite() || number.fract() != 0.0 {
+                        return Err(format!("printf() %d expects an integer, got {}", other));
+                    }
+                    text.push_str(&Value::Number(number).to_string());
+                },
+            },
+            'f' => text.push_str(&format!("{:.6}", number("printf", value)?)),
+            's' => text.push_str(&value.to_string()),
+            other => return Err(format!("printf() doesn't know the specifier '%{}'", other)),
+        }
+    }
+    if values.len() > 0 {
+        return Err(format!("printf() got {} arguments more than its format uses", values.len()));
+    }
+    interpreter.write_output(&text)?;
+    Ok(Value::Nil)
+}
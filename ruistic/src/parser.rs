@@ -2,43 +2,95 @@
 // This file is part of Ruistic, a custom programming language interpreter.
 // See LICENSE file for license information.
 
+use crate::error::Error;
 use crate::token::{Token, TokenType, Value};
 use std::boxed::Box;
+use std::cell::Cell;
+use std::rc::Rc;
 use crate::expression::Expr;
 use crate::expression::Expr::{Binary, Literal, Unary};
 use crate::token::TokenType::*;
 use crate::statement::Stmt;
 
+const MAX_ARGUMENTS: usize = 255;
+
+/// The kind of loop a `break` or `continue` would leave.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LoopKind {
+    Statement,
+    /// A `loop { ... }` expression, whose `break` may carry its value.
+    Expression,
+}
+
 pub struct Parser {
     tokens: Vec<Token>,
     pos: usize,
+    function_depth: usize,
+    loops: Vec<LoopKind>,
+    class_depth: usize,
+    /// The semicolon-less expressions ending a block of the function body
+    /// being parsed, by the `}` after each and the line the expression
+    /// starts on. `function` checks they are in tail position.
+    implicit_tails: Vec<(Token, usize)>,
+    implicit_return: bool,
 }
 
 impl Parser {
     pub fn new(tokens: Vec<Token>) -> Parser {
-        Parser { tokens, pos: 0 }
+        Parser { tokens, pos: 0, function_depth: 0, loops: Vec::new(), class_depth: 0, implicit_tails: Vec::new(), implicit_return: false }
+    }
+
+    /// In implicit-return mode, a function body's final expression, written
+    /// without its `;`, becomes the function's return value. That includes
+    /// the final expression of a block, `if` branch or `switch` arm that
+    /// ends the body, as in `if (c) { 1 } else { 2 }`.
+    pub fn set_implicit_return(&mut self, enabled: bool) {
+        self.implicit_return = enabled;
     }
 
     pub fn parse(&mut self) -> Vec<Stmt> {
+        let (statements, errors) = self.parse_all();
+        for e in errors {
+            eprintln!("{}", e.report());
+        }
+        statements
+    }
+
+    /// Parses the whole token stream, returning every error instead of
+    /// reporting it.
+    pub fn try_parse(&mut self) -> Result<Vec<Stmt>, Vec<Error>> {
+        let (statements, errors) = self.parse_all();
+        if errors.is_empty() { Ok(statements) } else { Err(errors) }
+    }
+
+    fn parse_all(&mut self) -> (Vec<Stmt>, Vec<Error>) {
         let mut statements = Vec::new();
+        let mut errors = Vec::new();
 
-        while !self.is_at_end() {
+        while !self.is_at_end() && !self.check(TokenType::EOF) {
             match self.declaration() {
                 Ok(stmt) => {
                     statements.push(stmt)
                 },
                 Err(e) => {
-                    eprintln!("Parsing error: {}", e);
+                    errors.push(e);
                     self.synchronize();
                 }
             }
-            if self.check(TokenType::EOF) {
-                break;
-            }
         }
-        statements
+        (statements, errors)
     }
 
+    /// Parses the whole token stream as a single expression, with no statement
+    /// grammar around it, for hosts such as a calculator.
+    pub fn parse_expression(&mut self) -> Result<Expr, Error> {
+        let expr = self.expression()?;
+        if !self.is_at_end() && !self.check(TokenType::EOF) {
+            let token = self.peek();
+            return Err(Error::parse(format!("Unexpected '{}' after expression", token.get_lexeme()), token));
+        }
+        Ok(*expr)
+    }
 
     fn is_at_end(&self) -> bool {
         self.pos >= self.tokens.len()
@@ -65,6 +117,10 @@ impl Parser {
         self.peek().get_type() == token_type
     }
 
+    fn check_next(&self, token_type: TokenType) -> bool {
+        self.tokens.get(self.pos + 1).is_some_and(|token| token.get_type() == token_type)
+    }
+
     fn advance(&mut self) -> &Token {
         if !self.is_at_end() {
             self.pos += 1;
@@ -72,12 +128,20 @@ impl Parser {
         self.previous()
     }
 
-    fn consume(&mut self, expected: TokenType, message: &str) -> Result<&Token, String> {
+    fn consume(&mut self, expected: TokenType, message: &str) -> Result<&Token, Error> {
         if self.check(expected) {
             return Ok(self.advance());
         }
 
-        Err(format!("{} at line {}", message, self.peek().get_line()))
+        Err(Error::parse(message, self.peek()))
+    }
+
+    fn consume_closing_paren(&mut self, open_line: usize, message: &str) -> Result<&Token, Error> {
+        if self.check(RIGHT_PAREN) {
+            return Ok(self.advance());
+        }
+
+        Err(Error::parse(format!("{} (unmatched '(' opened at line {})", message, open_line), self.peek()))
     }
 
     fn match_token_types(&mut self, types: &[TokenType]) -> bool {
@@ -91,15 +155,16 @@ impl Parser {
     }
 
     fn synchronize(&mut self) {
+        self.advance();
         while !self.is_at_end() {
             if self.previous().get_type() == SEMICOLON {
                 return;
             }
 
             match self.peek().get_type() {
-                CLASS | FUN | VAR |
-                FOR | IF | WHILE |
-                PRINT | RETURN => {
+                CLASS | FUN | VAR | CONST |
+                FOR | IF | WHILE | DO | SWITCH |
+                PRINT | WRITE | RETURN => {
                     return;
                 }
                 _ => {
@@ -109,27 +174,78 @@ impl Parser {
         }
     }
 
-    fn if_statement(&mut self) -> Result<Stmt, String> {
-        self.consume(LEFT_PAREN, "Expected '(' after 'if'")?;
+    fn if_statement(&mut self) -> Result<Stmt, Error> {
+        let line = self.previous().get_line();
+        let open_line = self.consume(LEFT_PAREN, "Expected '(' after 'if'")?.get_line();
         let condition = *self.expression()?;
-        self.consume(RIGHT_PAREN, "Expected ')' after 'if' condition")?;
+        self.consume_closing_paren(open_line, "Expected ')' after 'if' condition")?;
 
         let then_branch = Box::new(self.statement()?);
         let else_branch = if self.match_token_types(&[TokenType::ELSE]) {
             Some(Box::new(self.statement()?))
         } else { None };
-        return Ok(Stmt::If {condition, then_branch, else_branch});
+        return Ok(Stmt::If {condition, then_branch, else_branch, line});
+    }
+
+    fn switch_statement(&mut self) -> Result<Stmt, Error> {
+        let line = self.previous().get_line();
+        let open_line = self.consume(LEFT_PAREN, "Expected '(' after 'switch'")?.get_line();
+        let subject = *self.expression()?;
+        self.consume_closing_paren(open_line, "Expected ')' after 'switch' value")?;
+        self.consume(LEFT_BRACE, "Expected '{' before switch cases.")?;
+
+        let mut cases = Vec::new();
+        let mut default = None;
+        while !self.check(RIGHT_BRACE) && !self.is_at_end() {
+            if self.match_token_types(&[TokenType::CASE]) {
+                let value = *self.expression()?;
+                self.consume(COLON, "Expected ':' after case value.")?;
+                cases.push((value, self.case_body()?));
+            } else if self.match_token_types(&[TokenType::DEFAULT]) {
+                if default.is_some() {
+                    return Err(Error::parse("A switch can't have more than one 'default'", self.previous()));
+                }
+                self.consume(COLON, "Expected ':' after 'default'.")?;
+                default = Some(self.case_body()?);
+            } else {
+                return Err(Error::parse("Expected 'case' or 'default' in switch.", self.peek()));
+            }
+        }
+        self.consume(RIGHT_BRACE, "Expected '}' after switch cases.")?;
+        Ok(Stmt::Switch { subject, cases, default, line })
+    }
+
+    /// The statements of a `case` or `default` arm, up to the next arm or the
+    /// end of the switch.
+    fn case_body(&mut self) -> Result<Vec<Stmt>, Error> {
+        let mut statements = Vec::new();
+        while !self.check(TokenType::CASE) && !self.check(TokenType::DEFAULT) && !self.check(RIGHT_BRACE)
+            && !self.is_at_end() {
+            statements.push(self.declaration()?);
+        }
+        Ok(statements)
     }
 
-    fn print_statement(&mut self) -> Result<Stmt, String> {
-        let value = self.expression()?;
+    fn print_statement(&mut self) -> Result<Stmt, Error> {
+        let keyword = self.previous();
+        let (line, newline) = (keyword.get_line(), keyword.get_type() == TokenType::PRINT);
+        let mut values = vec![*self.expression()?];
+        while self.match_token_types(&[COMMA]) {
+            values.push(*self.expression()?);
+        }
         self.consume(TokenType::SEMICOLON, "Expect ';' after value.")?;
-        Ok(Stmt::Print(*value))
+        Ok(Stmt::Print { values, newline, line })
     }
 
-    fn for_statement(&mut self) -> Result<Stmt, String> {
-        self.consume(LEFT_PAREN, "Expected '(' after 'for'")?;
-        let initializer = if self.match_token_types(&[TokenType::VAR]) {
+    fn for_statement(&mut self) -> Result<Stmt, Error> {
+        let line = self.previous().get_line();
+        let open_line = self.consume(LEFT_PAREN, "Expected '(' after 'for'")?.get_line();
+        if self.check(TokenType::IDENTIFIER) && self.check_next(TokenType::IN) {
+            return self.for_range_statement(open_line);
+        }
+        let initializer = if self.match_token_types(&[TokenType::SEMICOLON]) {
+            None
+        } else if self.match_token_types(&[TokenType::VAR]) {
             Some(self.var_declaration()?)
         } else {
             Some(self.expression_statement()?)
@@ -146,43 +262,235 @@ impl Parser {
         } else {
             None
         };
-        self.consume(TokenType::RIGHT_PAREN, "Expect ')' after for loop.")?;
+        self.consume_closing_paren(open_line, "Expect ')' after for loop.")?;
 
-        let mut body = Box::new(self.statement()?);
-        if let Some(increment) = increment {
-            body = Box::new(Stmt::Block(vec![*body, Stmt::Expr(*increment)]));
-        }
-        let while_loop = Stmt::While {condition: *condition, body};
+        // The increment stays on the loop node rather than being appended to the
+        // body, so that `continue` skips the rest of the body but not the increment.
+        let body = Box::new(self.loop_body()?);
+        let while_loop = Stmt::While {condition: *condition, body, increment: increment.map(|increment| *increment), line};
         if let Some(init) = initializer {
-            return Ok(Stmt::Block(vec![init, while_loop]));
+            return Ok(Stmt::Block(vec![init, while_loop], line));
         }
         Ok(while_loop)
     }
 
-    fn while_statement(&mut self) -> Result<Stmt, String> {
-        self.consume(LEFT_PAREN, "Expected '(' after 'while'")?;
+    fn for_range_statement(&mut self, open_line: usize) -> Result<Stmt, Error> {
+        let name = self.advance().clone();
+        self.advance();
+        let start = self.expression()?;
+        if !self.match_token_types(&[TokenType::DOT_DOT, TokenType::DOT_DOT_EQUAL]) {
+            return Err(Error::parse("Expect '..' or '..=' in range.", self.peek()));
+        }
+        let inclusive = self.previous().get_type() == TokenType::DOT_DOT_EQUAL;
+        let end = self.expression()?;
+        self.consume_closing_paren(open_line, "Expect ')' after for range.")?;
+        let body = Box::new(self.loop_body()?);
+        Ok(Stmt::ForRange { name, start: *start, end: *end, inclusive, body })
+    }
+
+    fn return_statement(&mut self) -> Result<Stmt, Error> {
+        let keyword = self.previous().clone();
+        if self.function_depth == 0 {
+            return Err(Error::parse("Can't return from top-level code", &keyword));
+        }
+        let value = if !self.check(TokenType::SEMICOLON) {
+            Some(*self.expression()?)
+        } else {
+            None
+        };
+        self.consume(TokenType::SEMICOLON, "Expect ';' after return value.")?;
+        Ok(Stmt::Return { keyword, value })
+    }
+
+    fn while_statement(&mut self) -> Result<Stmt, Error> {
+        let line = self.previous().get_line();
+        let open_line = self.consume(LEFT_PAREN, "Expected '(' after 'while'")?.get_line();
         let condition = *self.expression()?;
-        self.consume(RIGHT_PAREN, "Expected ')' after 'while' condition")?;
-        let body = Box::new(self.statement()?);
+        self.consume_closing_paren(open_line, "Expected ')' after 'while' condition")?;
+        let body = Box::new(self.loop_body()?);
+
+        return Ok(Stmt::While {condition, body, increment: None, line});
+    }
+
+    fn do_while_statement(&mut self) -> Result<Stmt, Error> {
+        let line = self.previous().get_line();
+        let body = Box::new(self.loop_body()?);
+        self.consume(TokenType::WHILE, "Expected 'while' after 'do' body.")?;
+        let open_line = self.consume(LEFT_PAREN, "Expected '(' after 'while'")?.get_line();
+        let condition = *self.expression()?;
+        self.consume_closing_paren(open_line, "Expected ')' after 'while' condition")?;
+        self.consume(TokenType::SEMICOLON, "Expect ';' after 'do' loop.")?;
+        Ok(Stmt::DoWhile { body, condition, line })
+    }
 
-        return Ok(Stmt::While {condition, body});
+    fn loop_body(&mut self) -> Result<Stmt, Error> {
+        self.loops.push(LoopKind::Statement);
+        let body = self.statement();
+        self.loops.pop();
+        body
     }
 
-    fn expression_statement(&mut self) -> Result<Stmt, String> {
+    fn loop_expression(&mut self) -> Result<Box<Expr>, Error> {
+        let line = self.consume(LEFT_BRACE, "Expect '{' after 'loop'.")?.get_line();
+        self.loops.push(LoopKind::Expression);
+        let body = self.block();
+        self.loops.pop();
+        Ok(Box::new(Expr::Loop(Box::new(Stmt::Block(body?, line)))))
+    }
+
+    fn break_statement(&mut self) -> Result<Stmt, Error> {
+        let keyword = self.previous().clone();
+        let line = keyword.get_line();
+        let Some(&kind) = self.loops.last() else {
+            return Err(Error::parse("Can't use 'break' outside of a loop", &keyword));
+        };
+        let value = if !self.check(TokenType::SEMICOLON) {
+            if kind != LoopKind::Expression {
+                return Err(Error::parse("Can only break with a value out of a 'loop' expression", &keyword));
+            }
+            Some(*self.expression()?)
+        } else {
+            None
+        };
+        self.consume(TokenType::SEMICOLON, "Expect ';' after 'break'.")?;
+        Ok(Stmt::Break(value, line))
+    }
+
+    fn continue_statement(&mut self) -> Result<Stmt, Error> {
+        let line = self.previous().get_line();
+        if self.loops.is_empty() {
+            return Err(Error::parse("Can't use 'continue' outside of a loop", self.previous()));
+        }
+        self.consume(TokenType::SEMICOLON, "Expect ';' after 'continue'.")?;
+        Ok(Stmt::Continue(line))
+    }
+
+    fn expression_statement(&mut self) -> Result<Stmt, Error> {
+        let line = self.peek().get_line();
         let expr = self.expression()?;
+        if self.function_depth > 0 && self.check(RIGHT_BRACE) {
+            // The final expression of a function body may omit its `;`. It is
+            // taken as an implicit return, keyed by its `}`, until `function`
+            // knows whether it is in tail position.
+            let keyword = self.peek().clone();
+            self.implicit_tails.push((keyword.clone(), line));
+            return Ok(Stmt::Return { keyword, value: Some(*expr) });
+        }
         self.consume(TokenType::SEMICOLON, "Expect ';' after expression.")?;
-        Ok(Stmt::Expr(*expr))
+        Ok(Stmt::Expr(*expr, line))
     }
 
-    fn declaration(&mut self) -> Result<Stmt, String> {
-        if self.match_token_types(&[TokenType::VAR]) {
+    fn declaration(&mut self) -> Result<Stmt, Error> {
+        if self.match_token_types(&[TokenType::AT]) {
+            return Err(Error::parse("Annotations are not yet supported", self.previous()));
+        }
+        if self.match_token_types(&[TokenType::CLASS]) {
+            self.class_declaration()
+        } else if self.match_token_types(&[TokenType::FUN]) {
+            self.function("function")
+        } else if self.match_token_types(&[TokenType::VAR]) {
             self.var_declaration()
+        } else if self.match_token_types(&[TokenType::CONST]) {
+            self.const_declaration()
         } else {
             self.statement()
         }
     }
 
-    fn var_declaration(&mut self) -> Result<Stmt, String> {
+    fn class_declaration(&mut self) -> Result<Stmt, Error> {
+        let name = self.consume(TokenType::IDENTIFIER, "Expect class name.")?.clone();
+        self.consume(LEFT_BRACE, "Expect '{' before class body.")?;
+        let mut methods = Vec::new();
+        self.class_depth += 1;
+        while !self.check(RIGHT_BRACE) && !self.is_at_end() {
+            match self.function("method") {
+                Ok(method) => methods.push(method),
+                Err(error) => {
+                    self.class_depth -= 1;
+                    return Err(error);
+                }
+            }
+        }
+        self.class_depth -= 1;
+        self.consume(RIGHT_BRACE, "Expect '}' after class body.")?;
+        Ok(Stmt::Class { name, methods })
+    }
+
+    fn function(&mut self, kind: &str) -> Result<Stmt, Error> {
+        let name = self.consume(TokenType::IDENTIFIER, &format!("Expect {} name.", kind))?.clone();
+        self.consume(LEFT_PAREN, &format!("Expect '(' after {} name.", kind))?;
+        let mut params = Vec::new();
+        if !self.check(RIGHT_PAREN) {
+            loop {
+                if params.len() >= MAX_ARGUMENTS {
+                    return Err(Error::parse(format!("Can't have more than {} parameters", MAX_ARGUMENTS), self.peek()));
+                }
+                params.push(self.consume(TokenType::IDENTIFIER, "Expect parameter name.")?.clone());
+                if !self.match_token_types(&[COMMA]) {
+                    break;
+                }
+            }
+        }
+        self.consume(RIGHT_PAREN, "Expect ')' after parameters.")?;
+        self.consume(LEFT_BRACE, &format!("Expect '{{' before {} body.", kind))?;
+        let enclosing_tails = std::mem::take(&mut self.implicit_tails);
+        let enclosing_loops = std::mem::take(&mut self.loops);
+        self.function_depth += 1;
+        let body = self.block();
+        self.function_depth -= 1;
+        self.loops = enclosing_loops;
+        let mut tails = std::mem::replace(&mut self.implicit_tails, enclosing_tails);
+        let mut body = body?;
+        self.tail_returns(&mut body, &mut tails);
+        if let Some((brace, _)) = tails.first() {
+            return Err(Error::parse("Expect ';' after expression; only the final expression of a function may omit it.",
+                                    brace));
+        }
+        Ok(Stmt::Function { name, params, body: Rc::new(body) })
+    }
+
+    /// Strikes the implicit returns in tail position of `statements` off
+    /// `tails`. Outside implicit-return mode they become expression
+    /// statements, so the function returns nil as if each had its `;`.
+    fn tail_returns(&self, statements: &mut [Stmt], tails: &mut Vec<(Token, usize)>) {
+        if let Some(last) = statements.last_mut() {
+            self.tail_return(last, tails);
+        }
+    }
+
+    fn tail_return(&self, statement: &mut Stmt, tails: &mut Vec<(Token, usize)>) {
+        match statement {
+            Stmt::Return { keyword, value } if keyword.get_type() == RIGHT_BRACE => {
+                let position = (keyword.get_line(), keyword.get_column());
+                let Some(index) = tails.iter().position(|(brace, _)| (brace.get_line(), brace.get_column()) == position)
+                else {
+                    return;
+                };
+                let (_, line) = tails.remove(index);
+                if !self.implicit_return && let Some(value) = value.take() {
+                    *statement = Stmt::Expr(value, line);
+                }
+            },
+            Stmt::Block(statements, _) => self.tail_returns(statements, tails),
+            Stmt::If { then_branch, else_branch, .. } => {
+                self.tail_return(then_branch, tails);
+                if let Some(else_branch) = else_branch {
+                    self.tail_return(else_branch, tails);
+                }
+            },
+            Stmt::Switch { cases, default, .. } => {
+                for (_, body) in cases {
+                    self.tail_returns(body, tails);
+                }
+                if let Some(default) = default {
+                    self.tail_returns(default, tails);
+                }
+            },
+            _ => {},
+        }
+    }
+
+    fn var_declaration(&mut self) -> Result<Stmt, Error> {
         let name = self.consume(TokenType::IDENTIFIER, "Expect variable name.")?.clone();
         let mut initializer: Option<Expr> = None;
         if self.match_token_types(&[TokenType::EQUAL]) {
@@ -192,15 +500,34 @@ impl Parser {
         Ok(Stmt::Var(name, initializer))
     }
 
-    fn statement(&mut self) -> Result<Stmt, String> {
+    fn const_declaration(&mut self) -> Result<Stmt, Error> {
+        let name = self.consume(TokenType::IDENTIFIER, "Expect constant name.")?.clone();
+        self.consume(TokenType::EQUAL, "Expect '=' after constant name; a constant needs a value.")?;
+        let value = *self.expression()?;
+        self.consume(TokenType::SEMICOLON, "Expect ';' after value.")?;
+        Ok(Stmt::Const(name, value))
+    }
+
+    fn statement(&mut self) -> Result<Stmt, Error> {
         if self.match_token_types(&[TokenType::IF]) {
             return self.if_statement();
-        } else if self.match_token_types(&[TokenType::PRINT]) {
+        } else if self.match_token_types(&[TokenType::PRINT, TokenType::WRITE]) {
             return self.print_statement()
+        } else if self.match_token_types(&[TokenType::BREAK]) {
+            self.break_statement()
+        } else if self.match_token_types(&[TokenType::CONTINUE]) {
+            self.continue_statement()
+        } else if self.match_token_types(&[TokenType::RETURN]) {
+            self.return_statement()
         } else if self.match_token_types(&[TokenType::LEFT_BRACE]) {
-           return Ok(Stmt::Block(self.block()?))
+           let line = self.previous().get_line();
+           return Ok(Stmt::Block(self.block()?, line))
         } else if self.match_token_types(&[TokenType::WHILE]) {
             return self.while_statement();
+        } else if self.match_token_types(&[TokenType::DO]) {
+            self.do_while_statement()
+        } else if self.match_token_types(&[TokenType::SWITCH]) {
+            self.switch_statement()
         } else if self.match_token_types(&[TokenType::FOR]) {
             return self.for_statement();
         } else {
@@ -208,38 +535,103 @@ impl Parser {
         }
     }
 
-    fn block(&mut self) -> Result<Vec<Stmt>, String> {
+    fn block(&mut self) -> Result<Vec<Stmt>, Error> {
         let mut statements = Vec::new();
         while !self.check(TokenType::RIGHT_BRACE) && !self.is_at_end() {
-            if let Ok(statement) = self.declaration() {
-                statements.push(statement);
-            }
+            // Errors propagate so `parse` reports them and resynchronizes; dropping
+            // them here would retry the same unconsumed token forever.
+            statements.push(self.declaration()?);
         }
 
         self.consume(TokenType::RIGHT_BRACE, "Expected '}' after block.")?;
         Ok(statements)
     }
 
-    fn expression(&mut self) -> Result<Box<Expr>, String> {
+    fn expression(&mut self) -> Result<Box<Expr>, Error> {
         self.assignment()
     }
 
-    fn assignment(&mut self) -> Result<Box<Expr>, String> {
-        let expr = self.equality()?;
+    fn assignment(&mut self) -> Result<Box<Expr>, Error> {
+        let expr = self.conditional()?;
 
         if self.match_token_types(&[TokenType::EQUAL]) {
             let equals = self.previous().clone();
             let value = self.assignment()?;
 
-            if let Expr::Variable(name) = *expr {
-                 return Ok(Box::new(Expr::Assign{name, value}));
+            match *expr {
+                Expr::Variable { name, .. } => return Ok(Box::new(Expr::Assign { name, value, depth: Cell::new(None) })),
+                Expr::Get { object, name } => return Ok(Box::new(Expr::Set { object, name, value })),
+                Expr::Index { object, bracket, index } => return Ok(Box::new(Expr::IndexSet { object, bracket, index, value })),
+                _ => {}
             }
-            return Err(format!("Invalid assignment target at line {}", equals.get_line()));
+            return Err(Error::parse("Invalid assignment target", &equals));
+        }
+
+        // `x += e` is sugar for `x = x + e`, with `e` parsed as a whole.
+        if self.match_token_types(&[PLUS_EQUAL, MINUS_EQUAL, STAR_EQUAL, SLASH_EQUAL]) {
+            let compound = self.previous().clone();
+            let value = self.assignment()?;
+
+            let Expr::Variable { name, .. } = *expr else {
+                return Err(Error::parse("Invalid assignment target", &compound));
+            };
+            let (t, lexeme) = match compound.get_type() {
+                PLUS_EQUAL => (PLUS, "+"),
+                MINUS_EQUAL => (MINUS, "-"),
+                STAR_EQUAL => (STAR, "*"),
+                _ => (SLASH, "/"),
+            };
+            let operator = Token::new(t, lexeme.to_string(), None, compound.get_line(), compound.get_column());
+            let current = Box::new(Expr::Variable { name: name.clone(), depth: Cell::new(None) });
+            let value = Box::new(Expr::Binary { left: current, operator, right: value });
+            return Ok(Box::new(Expr::Assign { name, value, depth: Cell::new(None) }));
+        }
+        Ok(expr)
+    }
+
+    fn conditional(&mut self) -> Result<Box<Expr>, Error> {
+        let condition = self.logic_or()?;
+
+        if self.match_token_types(&[QUESTION]) {
+            let then_branch = self.expression()?;
+            self.consume(COLON, "Expect ':' after then branch of conditional expression.")?;
+            let else_branch = self.conditional()?;
+            return Ok(Box::new(Expr::Ternary { condition, then_branch, else_branch }));
+        }
+        Ok(condition)
+    }
+
+    fn logic_or(&mut self) -> Result<Box<Expr>, Error> {
+        let mut expr = self.logic_and()?;
+
+        while self.match_token_types(&[OR]) {
+            let operator = self.previous().clone();
+            let right = self.logic_and()?;
+            expr = Box::new(Expr::Logical {
+                left: expr,
+                operator,
+                right,
+            });
+        }
+        Ok(expr)
+    }
+
+    fn logic_and(&mut self) -> Result<Box<Expr>, Error> {
+        let mut expr = self.equality()?;
+
+        while self.match_token_types(&[AND]) {
+            let operator = self.previous().clone();
+            let right = self.equality()?;
+            expr = Box::new(Expr::Logical {
+                left: expr,
+                operator,
+                right,
+            });
         }
         Ok(expr)
     }
 
-    fn equality(&mut self) -> Result<Box<Expr>, String> {
+    fn equality(&mut self) -> Result<Box<Expr>, Error> {
         let mut expr = self.comparison()?;
 
         while self.match_token_types(&[TokenType::BANG_EQUAL, TokenType::EQUAL_EQUAL]) {
@@ -254,7 +646,7 @@ impl Parser {
         Ok(expr)
     }
 
-    fn comparison(&mut self) -> Result<Box<Expr>, String> {
+    fn comparison(&mut self) -> Result<Box<Expr>, Error> {
         let mut expr = self.term()?;
 
         while self.match_token_types(&[GREATER, GREATER_EQUAL, LESS, LESS_EQUAL]) {
@@ -269,7 +661,7 @@ impl Parser {
         Ok(expr)
     }
 
-    fn term(&mut self) -> Result<Box<Expr>, String> {
+    fn term(&mut self) -> Result<Box<Expr>, Error> {
         let mut expr = self.factor()?;
 
         while self.match_token_types(&[MINUS, PLUS]) {
@@ -284,7 +676,7 @@ impl Parser {
         Ok(expr)
     }
 
-    fn factor(&mut self) -> Result<Box<Expr>, String> {
+    fn factor(&mut self) -> Result<Box<Expr>, Error> {
         let mut expr = self.unary()?;
         while self.match_token_types(&[SLASH, STAR]) {
             let operator = self.previous().clone();
@@ -298,8 +690,8 @@ impl Parser {
         Ok(expr)
     }
 
-    fn unary(&mut self) -> Result<Box<Expr>, String> {
-        if self.match_token_types(&[BANG, MINUS]) {
+    fn unary(&mut self) -> Result<Box<Expr>, Error> {
+        if self.match_token_types(&[BANG, MINUS, PLUS]) {
             let operator = self.previous().clone();
             let right = self.unary()?;
             return Ok(Box::new(Unary {
@@ -307,10 +699,48 @@ impl Parser {
                 right,
             }));
         }
-        Ok(self.primary()?)
+        self.call()
     }
 
-    fn primary(&mut self) -> Result<Box<Expr>, String> {
+    fn call(&mut self) -> Result<Box<Expr>, Error> {
+        let mut expr = self.primary()?;
+        loop {
+            if self.match_token_types(&[LEFT_PAREN]) {
+                let open_line = self.previous().get_line();
+                expr = self.finish_call(expr, open_line)?;
+            } else if self.match_token_types(&[DOT]) {
+                let name = self.consume(TokenType::IDENTIFIER, "Expect property name after '.'.")?.clone();
+                expr = Box::new(Expr::Get { object: expr, name });
+            } else if self.match_token_types(&[LEFT_BRACKET]) {
+                let bracket = self.previous().clone();
+                let index = self.expression()?;
+                self.consume(RIGHT_BRACKET, "Expect ']' after index.")?;
+                expr = Box::new(Expr::Index { object: expr, bracket, index });
+            } else {
+                break;
+            }
+        }
+        Ok(expr)
+    }
+
+    fn finish_call(&mut self, callee: Box<Expr>, open_line: usize) -> Result<Box<Expr>, Error> {
+        let mut arguments = Vec::new();
+        if !self.check(RIGHT_PAREN) {
+            loop {
+                if arguments.len() >= MAX_ARGUMENTS {
+                    return Err(Error::parse(format!("Can't have more than {} arguments", MAX_ARGUMENTS), self.peek()));
+                }
+                arguments.push(*self.expression()?);
+                if !self.match_token_types(&[COMMA]) {
+                    break;
+                }
+            }
+        }
+        let paren = self.consume_closing_paren(open_line, "Expect ')' after arguments.")?.clone();
+        Ok(Box::new(Expr::Call { callee, paren, arguments }))
+    }
+
+    fn primary(&mut self) -> Result<Box<Expr>, Error> {
         if self.match_token_types(&[TokenType::FALSE]) {
             return Ok(Box::new(Expr::Literal(Value::Boolean(false))));
         }
@@ -322,8 +752,8 @@ impl Parser {
         }
         if self.match_token_types(&[TokenType::NUMBER]) {
             let token = self.previous().clone();
-            if let Some(Value::Number(n)) = token.literal {
-                return Ok(Box::new(Expr::Literal(Value::Number(n))));
+            if let Some(number @ (Value::Number(_) | Value::BigInt(_))) = token.literal {
+                return Ok(Box::new(Expr::Literal(number)));
             }
         }
         if self.match_token_types(&[TokenType::STRING]) {
@@ -333,13 +763,37 @@ impl Parser {
             }
         }
         if self.match_token_types(&[TokenType::LEFT_PAREN]) {
+            let open_line = self.previous().get_line();
             let expr = self.expression()?;
-            self.consume(TokenType::RIGHT_PAREN, "Expect ')' after expression.")?;
+            self.consume_closing_paren(open_line, "Expect ')' after expression.")?;
             return Ok(Box::new(Expr::Grouping(expr)));
         }
+        if self.match_token_types(&[TokenType::LEFT_BRACKET]) {
+            let mut elements = Vec::new();
+            if !self.check(RIGHT_BRACKET) {
+                loop {
+                    elements.push(*self.expression()?);
+                    if !self.match_token_types(&[COMMA]) {
+                        break;
+                    }
+                }
+            }
+            self.consume(RIGHT_BRACKET, "Expect ']' after list elements.")?;
+            return Ok(Box::new(Expr::ListLiteral(elements)));
+        }
+        if self.match_token_types(&[TokenType::LOOP]) {
+            return self.loop_expression();
+        }
+        if self.match_token_types(&[TokenType::THIS]) {
+            let keyword = self.previous().clone();
+            if self.class_depth == 0 {
+                return Err(Error::parse("Can't use 'this' outside of a class", &keyword));
+            }
+            return Ok(Box::new(Expr::This(keyword)));
+        }
         if self.match_token_types(&[TokenType::IDENTIFIER]) {
-            return Ok(Box::new(Expr::Variable(self.previous().clone())));
+            return Ok(Box::new(Expr::Variable { name: self.previous().clone(), depth: Cell::new(None) }));
         }
-        Err("Expected expression.".to_string())
+        Err(Error::parse("Expected expression.", self.peek()))
     }
 }
@@ -0,0 +1,282 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+use std::cell::Cell;
+use std::collections::HashMap;
+use crate::error::Error;
+use crate::expression::Expr;
+use crate::statement::Stmt;
+use crate::token::{Token, TokenType};
+
+/// A static pass run between parsing and interpreting. It records on every
+/// variable reference how many scopes out its declaration is, so a lookup
+/// finds the variable in scope where the code was written rather than
+/// whatever happens to be in scope when it runs.
+///
+/// The scopes mirror the environments the interpreter creates: one per
+/// block, per function call, per `for`-`in` iteration, and one holding
+/// `this` around a class's methods.
+pub struct Resolver {
+    /// Names declared in each enclosing local scope, innermost last, mapped
+    /// to whether their initializer has finished. The global scope is not
+    /// tracked: a name found in none of these is global.
+    scopes: Vec<HashMap<String, bool>>,
+    errors: Vec<Error>,
+    /// A line per variable reference resolved, when a report was asked for.
+    report: Option<Vec<String>>,
+    /// Whether the innermost enclosing function is a class's `init`.
+    in_initializer: bool,
+}
+
+impl Default for Resolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Resolver {
+    pub fn new() -> Self {
+        Self { scopes: Vec::new(), errors: Vec::new(), report: None, in_initializer: false }
+    }
+
+    /// Records how each variable reference resolves, for `report` to return.
+    pub fn with_report(mut self) -> Self {
+        self.report = Some(Vec::new());
+        self
+    }
+
+    /// A line per variable reference, in source order, giving the number of
+    /// scopes out its declaration is or that it is global, such as
+    /// `count at line 4, column 9: depth 1`. Empty unless made `with_report`.
+    pub fn report(&self) -> &[String] {
+        self.report.as_deref().unwrap_or_default()
+    }
+
+    /// Resolves every statement, returning all the errors found.
+    pub fn resolve(&mut self, statements: &[Stmt]) -> Result<(), Vec<Error>> {
+        self.statements(statements);
+        self.finish()
+    }
+
+    pub fn resolve_expression(&mut self, expr: &Expr) -> Result<(), Vec<Error>> {
+        self.expression(expr);
+        self.finish()
+    }
+
+    fn finish(&mut self) -> Result<(), Vec<Error>> {
+        let errors = std::mem::take(&mut self.errors);
+        if errors.is_empty() { Ok(()) } else { Err(errors) }
+    }
+
+    fn statements(&mut self, statements: &[Stmt]) {
+        for stmt in statements {
+            self.statement(stmt);
+        }
+    }
+
+    fn begin_scope(&mut self) {
+        self.scopes.push(HashMap::new());
+    }
+
+    fn end_scope(&mut self) {
+        self.scopes.pop();
+    }
+
+    /// Declares `name` in the innermost scope. Declaring a name twice in one
+    /// local scope is almost always a mistake; globals may be redeclared, as
+    /// a REPL session does all the time.
+    fn declare(&mut self, name: &Token) {
+        let Some(scope) = self.scopes.last_mut() else {
+            return;
+        };
+        if scope.insert(name.get_lexeme().to_string(), false).is_some() {
+            self.errors.push(Error::resolve("Already a variable with this name in this scope", name));
+        }
+    }
+
+    /// Marks `name` as initialized, so it may be read.
+    fn define(&mut self, name: &Token) {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(name.get_lexeme().to_string(), true);
+        }
+    }
+
+    fn declare_and_define(&mut self, name: &Token) {
+        self.declare(name);
+        self.define(name);
+    }
+
+    fn resolve_local(&mut self, name: &Token, depth: &Cell<Option<usize>>) {
+        depth.set(self.scopes.iter().rev().position(|scope| scope.contains_key(name.get_lexeme())));
+        if let Some(report) = &mut self.report {
+            let resolution = match depth.get() {
+                Some(depth) => format!("depth {}", depth),
+                None => "global".to_string(),
+            };
+            report.push(format!("{} at line {}, column {}: {}", name.get_lexeme(), name.get_line(), name.get_column(), resolution));
+        }
+    }
+
+    fn function(&mut self, params: &[Token], body: &[Stmt], initializer: bool) {
+        let enclosing = std::mem::replace(&mut self.in_initializer, initializer);
+        self.begin_scope();
+        for param in params {
+            self.declare_and_define(param);
+        }
+        self.statements(body);
+        self.end_scope();
+        self.in_initializer = enclosing;
+    }
+
+    fn statement(&mut self, stmt: &Stmt) {
+        match stmt {
+            Stmt::Expr(expr, _) => self.expression(expr),
+            Stmt::Print { values, .. } => {
+                for value in values {
+                    self.expression(value);
+                }
+            },
+            Stmt::Var(name, initializer) => {
+                self.declare(name);
+                if let Some(initializer) = initializer {
+                    self.expression(initializer);
+                }
+                self.define(name);
+            },
+            Stmt::Const(name, value) => {
+                self.declare(name);
+                self.expression(value);
+                self.define(name);
+            },
+            Stmt::Function { name, params, body } => {
+                // Defined first so the body can call itself.
+                self.declare_and_define(name);
+                self.function(params, body, false);
+            },
+            Stmt::Class { name, methods } => {
+                self.declare_and_define(name);
+                self.begin_scope();
+                self.scopes.last_mut().unwrap().insert("this".to_string(), true);
+                for method in methods {
+                    if let Stmt::Function { name, params, body } = method {
+                        self.function(params, body, name.get_lexeme() == "init");
+                    }
+                }
+                self.end_scope();
+            },
+            Stmt::Return { keyword, value: Some(value) } if self.in_initializer => {
+                // An implicit return of `init`'s final expression is simply
+                // discarded; only an explicit `return` is an error.
+                if keyword.get_type() == TokenType::RETURN {
+                    self.errors.push(Error::resolve("Can't return a value from an initializer", keyword));
+                }
+                self.expression(value);
+            },
+            Stmt::Return { value, .. } | Stmt::Break(value, _) => {
+                if let Some(value) = value {
+                    self.expression(value);
+                }
+            },
+            Stmt::Block(stmts, _) => {
+                self.begin_scope();
+                self.statements(stmts);
+                self.end_scope();
+            },
+            Stmt::Continue(_) => {},
+            Stmt::If { condition, then_branch, else_branch, .. } => {
+                self.expression(condition);
+                self.statement(then_branch);
+                if let Some(else_branch) = else_branch {
+                    self.statement(else_branch);
+                }
+            },
+            Stmt::While { condition, body, increment, .. } => {
+                self.expression(condition);
+                self.statement(body);
+                if let Some(increment) = increment {
+                    self.expression(increment);
+                }
+            },
+            Stmt::DoWhile { body, condition, .. } => {
+                self.statement(body);
+                self.expression(condition);
+            },
+            Stmt::Switch { subject, cases, default, .. } => {
+                self.expression(subject);
+                for (value, body) in cases {
+                    self.expression(value);
+                    self.begin_scope();
+                    self.statements(body);
+                    self.end_scope();
+                }
+                if let Some(default) = default {
+                    self.begin_scope();
+                    self.statements(default);
+                    self.end_scope();
+                }
+            },
+            Stmt::ForRange { name, start, end, body, .. } => {
+                self.expression(start);
+                self.expression(end);
+                self.begin_scope();
+                self.declare_and_define(name);
+                self.statement(body);
+                self.end_scope();
+            },
+        }
+    }
+
+    fn expression(&mut self, expr: &Expr) {
+        match expr {
+            Expr::Literal(_) | Expr::This(_) => {},
+            Expr::Unary { right, .. } => self.expression(right),
+            Expr::Binary { left, right, .. } | Expr::Logical { left, right, .. } => {
+                self.expression(left);
+                self.expression(right);
+            },
+            Expr::Ternary { condition, then_branch, else_branch } => {
+                self.expression(condition);
+                self.expression(then_branch);
+                self.expression(else_branch);
+            },
+            Expr::Grouping(expr) => self.expression(expr),
+            Expr::Call { callee, arguments, .. } => {
+                self.expression(callee);
+                for argument in arguments {
+                    self.expression(argument);
+                }
+            },
+            Expr::Get { object, .. } => self.expression(object),
+            Expr::Set { object, value, .. } => {
+                self.expression(object);
+                self.expression(value);
+            },
+            Expr::ListLiteral(elements) => {
+                for element in elements {
+                    self.expression(element);
+                }
+            },
+            Expr::Index { object, index, .. } => {
+                self.expression(object);
+                self.expression(index);
+            },
+            Expr::IndexSet { object, index, value, .. } => {
+                self.expression(object);
+                self.expression(index);
+                self.expression(value);
+            },
+            Expr::Loop(body) => self.statement(body),
+            Expr::Variable { name, depth } => {
+                if self.scopes.last().and_then(|scope| scope.get(name.get_lexeme())) == Some(&false) {
+                    self.errors.push(Error::resolve("Can't read local variable in its own initializer", name));
+                }
+                self.resolve_local(name, depth);
+            },
+            Expr::Assign { name, value, depth } => {
+                self.expression(value);
+                self.resolve_local(name, depth);
+            },
+        }
+    }
+}
@@ -4,95 +4,168 @@
 
 use std::collections::{HashMap};
 use once_cell::sync::Lazy;
+use std::rc::Rc;
+use crate::bigint::BigInt;
+use crate::error::Error;
 use crate::token;
 use crate::token::{Token, TokenType};
 
 static KEYWORDS: Lazy<HashMap<&'static str, TokenType>> = Lazy::new(|| {
     let mut m = HashMap::new();
     m.insert("and", TokenType::AND);
+    m.insert("break", TokenType::BREAK);
+    m.insert("case", TokenType::CASE);
     m.insert("class", TokenType::CLASS);
+    m.insert("const", TokenType::CONST);
+    m.insert("continue", TokenType::CONTINUE);
+    m.insert("default", TokenType::DEFAULT);
+    m.insert("do", TokenType::DO);
     m.insert("else", TokenType::ELSE);
     m.insert("false", TokenType::FALSE);
     m.insert("fun", TokenType::FUN);
     m.insert("for", TokenType::FOR);
     m.insert("if", TokenType::IF);
+    m.insert("in", TokenType::IN);
+    m.insert("loop", TokenType::LOOP);
     m.insert("nil", TokenType::NIL);
     m.insert("or", TokenType::OR);
     m.insert("print", TokenType::PRINT);
     m.insert("return", TokenType::RETURN);
     m.insert("super", TokenType::SUPER);
+    m.insert("switch", TokenType::SWITCH);
     m.insert("this", TokenType::THIS);
     m.insert("true", TokenType::TRUE);
     m.insert("var", TokenType::VAR);
     m.insert("while", TokenType::WHILE);
+    m.insert("write", TokenType::WRITE);
     m
 });
 pub struct Scanner {
-    src: String,
+    /// The source as chars, so `start` and `current` are char positions
+    /// even when the source contains multibyte characters.
+    src: Vec<char>,
     tokens: Vec<Token>,
     start: usize,
     current: usize,
     line: usize,
+    /// Column of the char at `current`, and of the token starting at `start`.
+    column: usize,
+    start_column: usize,
+    max_tokens: Option<usize>,
+    errors: Vec<Error>,
+    /// Problems that don't stop the source from running, such as a literal
+    /// that loses precision. The caller decides whether to show them.
+    warnings: Vec<String>,
 }
 
 impl Scanner {
     pub fn new(src: String) -> Self {
         Self {
-            src,
+            src: src.chars().collect(),
             tokens: Vec::new(),
             start: 0,
             current: 0,
             line: 1,
+            column: 1,
+            start_column: 1,
+            max_tokens: None,
+            errors: Vec::new(),
+            warnings: Vec::new(),
         }
     }
 
+    /// Rejects sources that scan to more than `limit` tokens, so an embedder
+    /// can bound the memory a hostile or runaway input takes.
+    pub fn with_max_tokens(mut self, limit: usize) -> Self {
+        self.max_tokens = Some(limit);
+        self
+    }
+
+    /// The warnings found by the last scan, in source order.
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
     fn is_at_end(&self) -> bool { self.current >= self.src.len() }
     fn advance(&mut self) -> char {
-        let c = self.src.chars().nth(self.current).unwrap_or('\0');
+        let c = self.src.get(self.current).copied().unwrap_or('\0');
         self.current += 1;
+        if c == '\n' {
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
         c
     }
 
     fn match_char(&mut self, expected: char) -> bool {
-        if self.is_at_end() || self.src.chars().nth(self.current).unwrap() != expected {
+        if self.is_at_end() || self.src[self.current] != expected {
             return false;
         }
-        self.current += 1;
+        self.advance();
         true
     }
     fn peek(&self) -> char {
-        if self.is_at_end() {
-            return '\0';
-        }
-        self.src.chars().nth(self.current).unwrap_or('\0')
+        self.src.get(self.current).copied().unwrap_or('\0')
     }
 
 
     fn peek_next(&self) -> char {
-        if self.current + 1 >= self.src.len() {
-            '\0'
-        } else {
-            self.src.chars().nth(self.current + 1).unwrap()
-        }
+        self.src.get(self.current + 1).copied().unwrap_or('\0')
+    }
+
+    fn text(&self, start: usize, end: usize) -> String {
+        self.src[start..end].iter().collect()
     }
     fn add_null_token(&mut self, t: TokenType) { self.add_token(t, None) }
     fn add_token(&mut self, t: TokenType, v: Option<token::Value>) {
-        let text = self.src[self.start..self.current].to_string();
-        self.tokens.push(Token::new(t, text, v, self.line));
+        let text = self.text(self.start, self.current);
+        self.tokens.push(Token::new(t, text, v, self.line, self.start_column));
     }
 
-    fn string(&mut self) -> Result<(), String> {
-        while self.peek() != '\"' {
-            if self.peek() == '\n' { self.line += 1;}
-            self.advance();
+    /// Records a scan error at the current line; scanning carries on.
+    fn error(&mut self, message: String) {
+        self.errors.push(Error::Scan { message, line: self.line });
+    }
+
+    fn string(&mut self) -> Result<(), Error> {
+        let mut value = String::new();
+        let mut error = None;
+        while self.peek() != '\"' && !self.is_at_end() {
+            let c = self.advance();
+            match c {
+                '\n' => {
+                    self.line += 1;
+                    value.push(c);
+                },
+                '\\' => match self.advance() {
+                    'n' => value.push('\n'),
+                    't' => value.push('\t'),
+                    'r' => value.push('\r'),
+                    '\\' => value.push('\\'),
+                    '\"' => value.push('\"'),
+                    '0' => value.push('\0'),
+                    escaped => {
+                        // Keep scanning to the closing quote so the rest of the
+                        // string isn't read as code.
+                        if escaped == '\n' {
+                            self.line += 1;
+                        }
+                        error.get_or_insert(Error::Scan { message: format!("Invalid escape sequence '\\{}'", escaped), line: self.line });
+                    },
+                },
+                _ => value.push(c),
+            }
         }
         if self.is_at_end() {
-            return Err("Unterminated string.".to_string())
+            return Err(Error::Scan { message: "Unterminated string".to_string(), line: self.line })
         }
 
         self.advance();
-        let lit = token::Value::String(self.src[self.start + 1..self.current - 1].to_string());
-        self.add_token(TokenType::STRING, Some(lit));
+        if let Some(error) = error {
+            return Err(error);
+        }
+        self.add_token(TokenType::STRING, Some(token::Value::String(value.into())));
         Ok(())
     }
 
@@ -100,24 +173,151 @@ impl Scanner {
         while self.peek().is_alphanumeric() || self.peek() == '_' {
             self.advance();
         }
-        let text = &self.src[self.start..self.current];
-        let token_type = KEYWORDS.get(text).cloned().unwrap_or(TokenType::IDENTIFIER);
+        let text = self.text(self.start, self.current);
+        let token_type = KEYWORDS.get(text.as_str()).cloned().unwrap_or(TokenType::IDENTIFIER);
         self.add_token(token_type, None);
     }
 
 
     fn number(&mut self) {
-        while self.peek().is_numeric() {
-            self.advance();
+        if self.src[self.start] == '0' && self.current == self.start + 1 {
+            let radix = match self.peek() {
+                'x' | 'X' => Some(16),
+                'o' | 'O' => Some(8),
+                'b' | 'B' => Some(2),
+                _ => None,
+            };
+            if let Some(radix) = radix {
+                self.advance();
+                self.radix_number(radix);
+                return;
+            }
         }
+        self.digits();
         if self.peek() == '.' && self.peek_next().is_numeric() {
             self.advance();
-            while self.peek().is_numeric() {
-                self.advance();
+            self.digits();
+        } else if self.peek() == '.' && self.peek_next() != '.' {
+            // `5.` is rejected rather than guessed at; the dot is consumed so the
+            // literal still scans as `5` and parsing can carry on.
+            let digits = self.text(self.start, self.current);
+            self.error(format!("Trailing '.' in number literal '{}.' (write '{}.0' or '{}')", digits, digits, digits));
+            let number = self.without_separators(&digits, 10).and_then(|digits| self.parse_number(&digits));
+            self.advance();
+            if let Some(number) = number {
+                self.add_token(TokenType::NUMBER, Some(token::Value::Number(number)));
             }
+            return;
+        }
+        if self.exponent() {
+            self.add_number_token();
+        }
+    }
+
+    fn leading_dot_number(&mut self) {
+        self.digits();
+        if self.exponent() {
+            self.add_number_token();
+        }
+    }
+
+    /// Scans the digits of a `0x`, `0o` or `0b` literal. Letters and digits
+    /// are all consumed so a bad digit like the `G` in `0xG` is an error rather
+    /// than the start of the next token.
+    fn radix_number(&mut self, radix: u32) {
+        while self.peek().is_ascii_alphanumeric() || self.peek() == '_' {
+            self.advance();
+        }
+        let text = self.text(self.start, self.current);
+        if text.len() == 2 {
+            self.error(format!("Missing digits after '{}'", text));
+            return;
+        }
+        let Some(digits) = self.without_separators(&text, radix) else {
+            return;
+        };
+        match i64::from_str_radix(&digits[2..], radix) {
+            Ok(number) => self.add_token(TokenType::NUMBER, Some(token::Value::Number(number as f64))),
+            Err(error) if *error.kind() == std::num::IntErrorKind::PosOverflow => {
+                self.error(format!("Number literal '{}' is too large", text));
+            },
+            Err(_) => self.error(format!("Invalid number literal '{}'", text)),
+        }
+    }
+
+    /// Consumes an optional `e`/`E` exponent with an optional sign. Returns
+    /// false, after reporting it, when the exponent has no digits (`2e`).
+    fn exponent(&mut self) -> bool {
+        if self.peek() != 'e' && self.peek() != 'E' {
+            return true;
+        }
+        self.advance();
+        if self.peek() == '+' || self.peek() == '-' {
+            self.advance();
+        }
+        if !self.peek().is_ascii_digit() {
+            self.error(format!("Missing digits in the exponent of number literal '{}'", self.text(self.start, self.current)));
+            return false;
+        }
+        self.digits();
+        true
+    }
+
+    /// Consumes a run of decimal digits, along with any `_` separators.
+    fn digits(&mut self) {
+        while self.peek().is_ascii_digit() || self.peek() == '_' {
+            self.advance();
+        }
+    }
+
+    /// Strips `_` digit separators, reporting any that don't sit between two
+    /// digits (`1_`, `1__0`, `0x_F`).
+    fn without_separators(&mut self, text: &str, radix: u32) -> Option<String> {
+        let chars: Vec<char> = text.chars().collect();
+        for (i, &c) in chars.iter().enumerate() {
+            let between_digits = i > 0 && chars[i - 1].is_digit(radix) && chars.get(i + 1).is_some_and(|next| next.is_digit(radix));
+            if c == '_' && !between_digits {
+                self.error(format!("Misplaced '_' in number literal '{}'", text));
+                return None;
+            }
+        }
+        Some(text.replace('_', ""))
+    }
+
+    fn add_number_token(&mut self) {
+        let Some(text) = self.without_separators(&self.text(self.start, self.current), 10) else {
+            return;
+        };
+        // Integer literals beyond i64 keep every digit instead of rounding.
+        if text.parse::<i64>().is_err() && let Some(big) = BigInt::parse(&text) {
+            self.add_token(TokenType::NUMBER, Some(token::Value::BigInt(Rc::new(big))));
+            return;
+        }
+        if let Some(number) = self.parse_number(&text) {
+            self.add_token(TokenType::NUMBER, Some(token::Value::Number(number)));
+        }
+    }
+
+    fn parse_number(&mut self, text: &str) -> Option<f64> {
+        match text.parse::<f64>() {
+            Ok(number) if number.is_infinite() => {
+                self.error(format!("Number literal '{}' is too large", text));
+                None
+            },
+            Ok(number) => {
+                let digits = text.trim_start_matches('0');
+                if text.bytes().all(|b| b.is_ascii_digit()) && !digits.is_empty() && digits != format!("{:.0}", number) {
+                    self.warnings.push(format!(
+                        "integer literal '{}' at line {} cannot be represented exactly; it is rounded to {:.0}",
+                        text, self.line, number));
+                }
+                Some(number)
+            },
+            Err(_) => {
+                self.error(format!("Invalid number literal '{}'", text));
+                None
+            },
         }
-        let literal = token::Value::Number(self.src[self.start..self.current].parse::<f64>().unwrap());
-        self.add_token(TokenType::NUMBER, Some(literal));
     }
 
     fn scan_token(&mut self) {
@@ -127,24 +327,50 @@ impl Scanner {
             ')' => self.add_null_token(TokenType::RIGHT_PAREN),
             '{' => self.add_null_token(TokenType::LEFT_BRACE),
             '}' => self.add_null_token(TokenType::RIGHT_BRACE),
+            '[' => self.add_null_token(TokenType::LEFT_BRACKET),
+            ']' => self.add_null_token(TokenType::RIGHT_BRACKET),
             ',' => self.add_null_token(TokenType::COMMA),
-            '.' => self.add_null_token(TokenType::DOT),
-            '-' => self.add_null_token(TokenType::MINUS),
-            '+' => self.add_null_token(TokenType::PLUS),
+            '.' => {
+                // `..` and `..=` are range operators; `.5` is a number; `a.b`
+                // (no digit after the dot) stays a DOT.
+                if self.match_char('.') {
+                    if self.match_char('=') {
+                        self.add_null_token(TokenType::DOT_DOT_EQUAL)
+                    } else {
+                        self.add_null_token(TokenType::DOT_DOT)
+                    }
+                } else if self.peek().is_numeric() {
+                    self.leading_dot_number();
+                } else {
+                    self.add_null_token(TokenType::DOT)
+                }
+            },
+            '-' => {
+                if self.match_char('=') {
+                    self.add_null_token(TokenType::MINUS_EQUAL)
+                } else {
+                    self.add_null_token(TokenType::MINUS)
+                }
+            },
+            '+' => {
+                if self.match_char('=') {
+                    self.add_null_token(TokenType::PLUS_EQUAL)
+                } else {
+                    self.add_null_token(TokenType::PLUS)
+                }
+            },
             ';' => self.add_null_token(TokenType::SEMICOLON),
             '/' => {
                 if self.match_char('/') {
-                    while !self.match_char('\n') {
-                        if self.match_char('\n') {
-                            self.line += 1;
-                            break;
-                        }
+                    // Leave the newline for the '\n' arm so the line count stays right.
+                    while self.peek() != '\n' && !self.is_at_end() {
+                        self.advance();
                     }
                 } else if self.match_char('*') {
                     let mut depth = 1;
                     while depth > 0 {
                         if self.is_at_end() {
-                            eprintln!("Unterminated block comment");
+                            self.error("Unterminated block comment".to_string());
                             return;
                         }
                         if self.peek() == '\n' {
@@ -160,12 +386,23 @@ impl Scanner {
                         }
                         self.advance();
                     }
+                } else if self.match_char('=') {
+                    self.add_null_token(TokenType::SLASH_EQUAL);
                 }
                 else {
                     self.add_null_token(TokenType::SLASH);
                 }
             },
-            '*' => self.add_null_token(TokenType::STAR),
+            '*' => {
+                if self.match_char('=') {
+                    self.add_null_token(TokenType::STAR_EQUAL)
+                } else {
+                    self.add_null_token(TokenType::STAR)
+                }
+            },
+            '@' => self.add_null_token(TokenType::AT),
+            '?' => self.add_null_token(TokenType::QUESTION),
+            ':' => self.add_null_token(TokenType::COLON),
             '!' => {
                 if self.match_char('=') {
                     self.add_null_token(TokenType::BANG_EQUAL)
@@ -194,12 +431,27 @@ impl Scanner {
                     self.add_null_token(TokenType::GREATER)
                 }
             },
+            // `&&` and `||` are spellings of the `and`/`or` keywords. A single
+            // `&` or `|` is not an operator and falls through as unrecognized.
+            '&' => {
+                if self.match_char('&') {
+                    self.add_null_token(TokenType::AND)
+                } else {
+                    self.error(format!("Unrecognized character '{}'", c))
+                }
+            },
+            '|' => {
+                if self.match_char('|') {
+                    self.add_null_token(TokenType::OR)
+                } else {
+                    self.error(format!("Unrecognized character '{}'", c))
+                }
+            },
             ' ' | '\r' | '\t' => {},
             '\n' => self.line += 1,
             '\"' => {
-                match self.string() {
-                    Ok(_) => {},
-                    Err(e) => eprintln!("{}", e)
+                if let Err(e) = self.string() {
+                    self.errors.push(e);
                 }
             },
             'a'..='z' | 'A'..='Z' | '_' => {
@@ -210,17 +462,89 @@ impl Scanner {
                 self.start = self.current - 1;
                 self.number();
             }
-            _ => { eprintln!("Unrecognized character: {}", c); return }
+            _ => self.error(format!("Unrecognized character '{}'", c)),
         }
     }
 
+    /// Scans the whole source, reporting errors and keeping the tokens around
+    /// them. If a token limit is exceeded only EOF is returned, so nothing of
+    /// the input runs.
     pub fn scan_tokens(mut self) -> Vec<Token> {
+        let within_limit = self.scan();
+        for warning in &self.warnings {
+            eprintln!("Warning: {}", warning);
+        }
+        for error in &self.errors {
+            eprintln!("{}", error.report());
+        }
+        if within_limit {
+            self.tokens
+        } else {
+            vec![Token::new(TokenType::EOF, "".to_string(), None, 1, 1)]
+        }
+    }
+
+    /// Scans the whole source, returning every error instead of reporting it.
+    /// Warnings are kept for `warnings` to return.
+    pub fn try_scan_tokens(&mut self) -> Result<Vec<Token>, Vec<Error>> {
+        self.scan();
+        self.take_result()
+    }
+
+    /// Scans the source like `try_scan_tokens`, but keeps the tokens of
+    /// `old_src` that end before its first edit and scans only from there,
+    /// so an editor re-tokenizing after a keystroke pays for the text from
+    /// the edit onwards. `old_tokens` must come from scanning `old_src`
+    /// without errors.
+    pub fn rescan(&mut self, old_src: &str, old_tokens: Vec<Token>) -> Result<Vec<Token>, Vec<Error>> {
+        let old: Vec<char> = old_src.chars().collect();
+        let changed = old.iter().zip(&self.src).take_while(|(old, new)| old == new).count();
+        let mut line_starts = vec![0];
+        line_starts.extend(old.iter().enumerate().filter(|(_, c)| **c == '\n').map(|(i, _)| i + 1));
+
+        for token in old_tokens {
+            if token.get_type() == TokenType::EOF {
+                break;
+            }
+            // A token's line is the one it ends on; its column is where it starts.
+            let lexeme = token.get_lexeme();
+            let end_column = match lexeme.rfind('\n') {
+                Some(newline) => lexeme[newline + 1..].chars().count() + 1,
+                None => token.get_column() + lexeme.chars().count(),
+            };
+            let end = line_starts[token.get_line() - 1] + end_column - 1;
+            // A token ending right at the edit may be extended by it.
+            if end >= changed {
+                break;
+            }
+            (self.current, self.line, self.column) = (end, token.get_line(), end_column);
+            self.tokens.push(token);
+        }
+        self.scan();
+        self.take_result()
+    }
+
+    fn take_result(&mut self) -> Result<Vec<Token>, Vec<Error>> {
+        if self.errors.is_empty() {
+            Ok(std::mem::take(&mut self.tokens))
+        } else {
+            Err(std::mem::take(&mut self.errors))
+        }
+    }
+
+    /// Returns false, with the error recorded, once the token limit is exceeded.
+    fn scan(&mut self) -> bool {
         while !self.is_at_end() {
             self.start = self.current;
+            self.start_column = self.column;
             self.scan_token();
+            if let Some(limit) = self.max_tokens && self.tokens.len() > limit {
+                self.error(format!("Source exceeds the limit of {} tokens", limit));
+                return false;
+            }
         }
-        self.tokens.push(Token::new(TokenType::EOF, "".to_string(), None, self.line));
-        self.tokens
+        self.tokens.push(Token::new(TokenType::EOF, "".to_string(), None, self.line, self.column));
+        true
     }
 
 }
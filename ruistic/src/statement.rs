@@ -2,21 +2,162 @@
 // This file is part of Ruistic, a custom programming language interpreter.
 // See LICENSE file for license information.
 
-use crate::expression::Expr;
+use std::fmt;
+use std::rc::Rc;
+use crate::expression::{spaced, Expr};
 use crate::token::*;
+/// A statement. Variants without a token of their own carry the line they
+/// start on, so every statement can be reported by line; see `Stmt::line`.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Stmt {
-    Expr(Expr),
-    Print(Expr),
+    Expr(Expr, usize),
+    /// `print a, b;` writes its values separated by spaces and then a
+    /// newline; `write` is the same without the newline.
+    Print {
+        values: Vec<Expr>,
+        newline: bool,
+        line: usize,
+    },
     Var(Token, Option<Expr>),
-    Block(Vec<Stmt>),
+    /// `const name = value;`, a variable that can't be assigned again.
+    Const(Token, Expr),
+    Function {
+        name: Token,
+        params: Vec<Token>,
+        body: Rc<Vec<Stmt>>,
+    },
+    Class {
+        name: Token,
+        methods: Vec<Stmt>,
+    },
+    Return {
+        keyword: Token,
+        value: Option<Expr>,
+    },
+    Block(Vec<Stmt>, usize),
+    Break(Option<Expr>, usize),
+    Continue(usize),
     If {
         condition: Expr,
         then_branch: Box<Stmt>,
         else_branch: Option<Box<Stmt>>,
+        line: usize,
     },
     While {
         condition: Expr,
         body: Box<Stmt>,
+        increment: Option<Expr>,
+        line: usize,
+    },
+    /// `do body while (condition);`, which runs the body before the first
+    /// check of the condition.
+    DoWhile {
+        body: Box<Stmt>,
+        condition: Expr,
+        line: usize,
+    },
+    /// `switch (subject) { case a: ... default: ... }`. Only the first case
+    /// whose value `==` the subject runs, or else the default; there is no
+    /// fallthrough. Each arm's statements run in a scope of their own.
+    Switch {
+        subject: Expr,
+        cases: Vec<(Expr, Vec<Stmt>)>,
+        default: Option<Vec<Stmt>>,
+        line: usize,
+    },
+    /// `for (name in start..end)`, counting up by one; `..=` includes `end`.
+    ForRange {
+        name: Token,
+        start: Expr,
+        end: Expr,
+        inclusive: bool,
+        body: Box<Stmt>,
     },
 }
+
+impl Stmt {
+    /// The line of the statement's leading token.
+    pub fn line(&self) -> usize {
+        match self {
+            Stmt::Expr(_, line) | Stmt::Block(_, line)
+            | Stmt::Break(_, line) | Stmt::Continue(line) => *line,
+            Stmt::Print { line, .. } | Stmt::If { line, .. } | Stmt::While { line, .. }
+            | Stmt::DoWhile { line, .. } | Stmt::Switch { line, .. } => *line,
+            Stmt::Var(name, _) | Stmt::Const(name, _) | Stmt::Function { name, .. } | Stmt::Class { name, .. }
+            | Stmt::ForRange { name, .. } => name.get_line(),
+            Stmt::Return { keyword, .. } => keyword.get_line(),
+        }
+    }
+}
+
+/// The statement as an S-expression, in the form `Expr` displays.
+impl fmt::Display for Stmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stmt::Expr(expr, _) => write!(f, "(expr {})", expr),
+            Stmt::Print { values, newline, .. } => {
+                write!(f, "({}", if *newline { "print" } else { "write" })?;
+                spaced(f, values)?;
+                write!(f, ")")
+            },
+            Stmt::Var(name, Some(initializer)) => write!(f, "(var {} {})", name.get_lexeme(), initializer),
+            Stmt::Var(name, None) => write!(f, "(var {})", name.get_lexeme()),
+            Stmt::Const(name, value) => write!(f, "(const {} {})", name.get_lexeme(), value),
+            Stmt::Function { name, params, body } => {
+                let params: Vec<&str> = params.iter().map(Token::get_lexeme).collect();
+                write!(f, "(fun {} ({})", name.get_lexeme(), params.join(" "))?;
+                spaced(f, body)?;
+                write!(f, ")")
+            },
+            Stmt::Class { name, methods } => {
+                write!(f, "(class {}", name.get_lexeme())?;
+                spaced(f, methods)?;
+                write!(f, ")")
+            },
+            Stmt::Return { value: Some(value), .. } => write!(f, "(return {})", value),
+            Stmt::Return { value: None, .. } => write!(f, "(return)"),
+            Stmt::Block(stmts, _) => {
+                write!(f, "(block")?;
+                spaced(f, stmts)?;
+                write!(f, ")")
+            },
+            Stmt::Break(Some(value), _) => write!(f, "(break {})", value),
+            Stmt::Break(None, _) => write!(f, "(break)"),
+            Stmt::Continue(_) => write!(f, "(continue)"),
+            Stmt::If { condition, then_branch, else_branch, .. } => {
+                write!(f, "(if {} {}", condition, then_branch)?;
+                if let Some(else_branch) = else_branch {
+                    write!(f, " {}", else_branch)?;
+                }
+                write!(f, ")")
+            },
+            Stmt::While { condition, body, increment, .. } => {
+                write!(f, "(while {} {}", condition, body)?;
+                if let Some(increment) = increment {
+                    write!(f, " {}", increment)?;
+                }
+                write!(f, ")")
+            },
+            Stmt::DoWhile { body, condition, .. } => write!(f, "(do {} {})", body, condition),
+            Stmt::Switch { subject, cases, default, .. } => {
+                write!(f, "(switch {}", subject)?;
+                for (value, body) in cases {
+                    write!(f, " (case {}", value)?;
+                    spaced(f, body)?;
+                    write!(f, ")")?;
+                }
+                if let Some(default) = default {
+                    write!(f, " (default")?;
+                    spaced(f, default)?;
+                    write!(f, ")")?;
+                }
+                write!(f, ")")
+            },
+            Stmt::ForRange { name, start, end, inclusive, body } => {
+                let range = if *inclusive { "..=" } else { ".." };
+                write!(f, "(for {} ({} {} {}) {})", name.get_lexeme(), range, start, end, body)
+            },
+        }
+    }
+}
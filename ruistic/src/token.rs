@@ -2,47 +2,211 @@
 // This file is part of Ruistic, a custom programming language interpreter.
 // See LICENSE file for license information.
 
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+use crate::bigint::BigInt;
+use crate::class::{Class, Instance};
+use crate::function::Function;
+use crate::native::Native;
+
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum TokenType {
     // Single-character tokens.
-    LEFT_PAREN, RIGHT_PAREN, LEFT_BRACE, RIGHT_BRACE,
-    COMMA, DOT, MINUS, PLUS, SEMICOLON, SLASH, STAR,
+    LEFT_PAREN, RIGHT_PAREN, LEFT_BRACE, RIGHT_BRACE, LEFT_BRACKET, RIGHT_BRACKET,
+    COMMA, DOT, MINUS, PLUS, SEMICOLON, SLASH, STAR, AT,
+    QUESTION, COLON,
 
     // One or two character tokens.
     BANG, BANG_EQUAL, EQUAL, EQUAL_EQUAL,
     GREATER, GREATER_EQUAL, LESS, LESS_EQUAL,
+    DOT_DOT, DOT_DOT_EQUAL,
+    PLUS_EQUAL, MINUS_EQUAL, STAR_EQUAL, SLASH_EQUAL,
 
     // Literals.
     IDENTIFIER, STRING, NUMBER,
 
     // Keywords.
-    AND, CLASS, ELSE, FALSE, FUN, FOR, IF, NIL, OR,
-    PRINT, RETURN, SUPER, THIS, TRUE, VAR, WHILE,
+    AND, BREAK, CASE, CLASS, CONST, CONTINUE, DEFAULT, DO, ELSE, FALSE, FUN, FOR, IF,
+    IN, LOOP, NIL, OR, PRINT, RETURN, SUPER, SWITCH, THIS, TRUE, VAR, WHILE, WRITE,
 
     EOF
 }
 
+/// `==` in scripts is this type's `PartialEq`: a `Number` and a `BigInt`
+/// are equal when they hold the same integer, and otherwise values of
+/// different variants are never equal, so `nil` equals only `nil`, whatever
+/// variants are added.
+///
+/// Serializing covers the values a literal in the syntax tree can hold; the
+/// runtime-only variants fail to serialize.
 #[allow(dead_code)]
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Value {
     Number(f64),
-    String(String),
+    /// An integer too large for `Number` to hold exactly. Arithmetic that
+    /// brings it back into range returns a `Number` again.
+    BigInt(Rc<BigInt>),
+    /// Shared, so copying a string value between variables doesn't copy its
+    /// text, and growable, so `s = s + x` can append to a string no other
+    /// value shares.
+    String(Rc<String>),
     Boolean(bool),
+    /// Shared and mutable: every copy of a list value sees writes through
+    /// any of them.
+    List(Rc<RefCell<Vec<Value>>>),
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
+    Function(Rc<Function>),
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
+    Native(Rc<Native>),
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
+    Class(Rc<Class>),
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
+    Instance(Instance),
     Nil
 }
 
-#[derive(Debug, Clone)]
+/// The address of a list, to recognize it again when it contains itself.
+type ListAddress = *const RefCell<Vec<Value>>;
+
+impl Value {
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Value::Number(_) | Value::BigInt(_) => "number",
+            Value::String(_) => "string",
+            Value::Boolean(_) => "bool",
+            Value::List(_) => "list",
+            Value::Function(_) | Value::Native(_) => "function",
+            Value::Class(_) => "class",
+            Value::Instance(_) => "instance",
+            Value::Nil => "nil",
+        }
+    }
+
+    /// How the REPL echoes a value: as it would be written in source, so a
+    /// string is quoted. Other values look as they print.
+    pub fn repr(&self) -> String {
+        match self {
+            Value::String(string) => format!("{:?}", string),
+            other => other.to_string(),
+        }
+    }
+
+    /// `==`, where `comparing` holds the pairs of lists whose elements are
+    /// being compared further up. Meeting such a pair again means a list
+    /// contains itself; the pair is taken as equal so far, leaving the
+    /// outcome to the elements still to be compared.
+    fn equals(&self, other: &Self, comparing: &mut Vec<(ListAddress, ListAddress)>) -> bool {
+        match (self, other) {
+            (Value::Number(a), Value::Number(b)) => a == b,
+            (Value::BigInt(a), Value::BigInt(b)) => a == b,
+            // A `BigInt` can hold an integer an `f64` also holds exactly, such
+            // as 10^22, so the two kinds compare by value.
+            (Value::BigInt(big), Value::Number(number)) | (Value::Number(number), Value::BigInt(big)) => {
+                number.is_finite() && number.fract() == 0.0 && BigInt::from_f64(*number) == **big
+            },
+            (Value::String(a), Value::String(b)) => a == b,
+            (Value::Boolean(a), Value::Boolean(b)) => a == b,
+            (Value::List(a), Value::List(b)) => {
+                let pair = (Rc::as_ptr(a), Rc::as_ptr(b));
+                if Rc::ptr_eq(a, b) || comparing.contains(&pair) {
+                    return true;
+                }
+                let (a, b) = (a.borrow(), b.borrow());
+                if a.len() != b.len() {
+                    return false;
+                }
+                comparing.push(pair);
+                let equal = a.iter().zip(b.iter()).all(|(a, b)| a.equals(b, comparing));
+                comparing.pop();
+                equal
+            },
+            (Value::Function(a), Value::Function(b)) => a == b,
+            (Value::Native(a), Value::Native(b)) => a == b,
+            (Value::Class(a), Value::Class(b)) => a == b,
+            (Value::Instance(a), Value::Instance(b)) => a == b,
+            (Value::Nil, Value::Nil) => true,
+            _ => false,
+        }
+    }
+}
+
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        self.equals(other, &mut Vec::new())
+    }
+}
+
+/// Formats a number the way reference Lox does: an integral value has no
+/// fractional part, however large, and negative zero prints as `0`.
+fn format_number(number: f64) -> String {
+    if number == 0.0 {
+        return "0".to_string();
+    }
+    // `f64`'s `Display` never switches to exponent notation, so whole
+    // numbers already come out without a `.0`.
+    number.to_string()
+}
+
+/// How `print` shows a value. A whole number prints without `.0`, and a
+/// string prints bare unless it is inside a list, which is shown in literal
+/// syntax (`[1, "a"]`) so its elements can be told apart.
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Number(number) => write!(f, "{}", format_number(*number)),
+            Value::BigInt(number) => write!(f, "{}", number),
+            Value::String(string) => write!(f, "{}", string),
+            Value::Boolean(boolean) => write!(f, "{}", boolean),
+            Value::List(list) => write_list(f, list, &mut Vec::new()),
+            Value::Function(function) => write!(f, "<fn {}>", function.name()),
+            Value::Native(native) => write!(f, "<native fn {}>", native.name()),
+            Value::Class(class) => write!(f, "{}", class.name()),
+            Value::Instance(instance) => write!(f, "{} instance", instance.class().name()),
+            Value::Nil => write!(f, "nil"),
+        }
+    }
+}
+
+/// Writes a list in literal syntax. `enclosing` holds the lists being
+/// written further up, so a list that contains itself shows as `[...]`
+/// where it recurs instead of being written forever.
+fn write_list(f: &mut fmt::Formatter<'_>, list: &Rc<RefCell<Vec<Value>>>, enclosing: &mut Vec<ListAddress>) -> fmt::Result {
+    if enclosing.contains(&Rc::as_ptr(list)) {
+        return write!(f, "[...]");
+    }
+    enclosing.push(Rc::as_ptr(list));
+    write!(f, "[")?;
+    for (i, element) in list.borrow().iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        match element {
+            Value::List(element) => write_list(f, element, enclosing)?,
+            element => write!(f, "{}", element.repr())?,
+        }
+    }
+    enclosing.pop();
+    write!(f, "]")
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Token {
+    #[cfg_attr(feature = "serde", serde(rename = "type"))]
     t: TokenType,
     lexeme: String,
     pub(crate) literal: Option<Value>,
     line: usize,
+    column: usize,
 }
 #[allow(dead_code)]
 impl Token {
-    pub fn new(t: TokenType, lexeme: String, literal: Option<Value>, line: usize) -> Token {
-        Token { t, lexeme, literal, line }
+    pub fn new(t: TokenType, lexeme: String, literal: Option<Value>, line: usize, column: usize) -> Token {
+        Token { t, lexeme, literal, line, column }
     }
     pub fn get_type(&self) -> TokenType {
         self.t.clone()
@@ -56,4 +220,20 @@ impl Token {
     pub fn get_line(&self) -> usize {
         self.line
     }
+    /// The 1-based column of the token's first character.
+    pub fn get_column(&self) -> usize {
+        self.column
+    }
+}
+
+/// The type, the quoted lexeme, the literal value if there is one, and the
+/// line, as in `NUMBER "1" 1 line 1`.
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} {:?}", self.t, self.lexeme)?;
+        if let Some(literal) = &self.literal {
+            write!(f, " {}", literal)?;
+        }
+        write!(f, " line {}", self.line)
+    }
 }
@@ -0,0 +1,26 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+use ruistic::{Error, Parser, Scanner, TokenType};
+
+fn parse_errors(src: &str) -> Vec<Error> {
+    let tokens = Scanner::new(src.to_string()).try_scan_tokens().unwrap();
+    Parser::new(tokens).try_parse().unwrap_err()
+}
+
+#[test]
+fn at_sign_scans_to_its_own_token() {
+    let tokens = Scanner::new("@foo".to_string()).try_scan_tokens().unwrap();
+    let types: Vec<TokenType> = tokens.iter().map(|token| token.get_type()).collect();
+    assert_eq!(types, vec![TokenType::AT, TokenType::IDENTIFIER, TokenType::EOF]);
+    assert_eq!(tokens[1].get_lexeme(), "foo");
+}
+
+#[test]
+fn annotation_is_a_parse_error() {
+    let errors = parse_errors("@foo\nfun f() {}");
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(&errors[0], Error::Parse { message, line: 1, column: 1 }
+                     if message == "Annotations are not yet supported"), "{:?}", errors);
+}
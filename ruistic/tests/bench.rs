@@ -0,0 +1,36 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+use std::process::Command;
+use ruistic::try_run;
+
+const PROGRAMS: &[(&str, &str)] = &[
+    ("fibonacci", include_str!("../benches/fibonacci.ru")),
+    ("loops", include_str!("../benches/loops.ru")),
+    ("strings", include_str!("../benches/strings.ru")),
+    ("concat", include_str!("../benches/concat.ru")),
+    ("variables", include_str!("../benches/variables.ru")),
+];
+
+#[test]
+fn sample_programs_run_without_error() {
+    for (name, src) in PROGRAMS {
+        let result = try_run(src);
+        assert_eq!(result.errors, vec![], "{} failed: {}", name, result.stderr);
+    }
+}
+
+#[test]
+fn bench_reports_a_row_per_program() {
+    let output = Command::new(env!("CARGO_BIN_EXE_ruistic")).arg("--bench").output().unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let header = stdout.lines().position(|line| line.starts_with("program")).expect("a header row");
+    let rows: Vec<&str> = stdout.lines().skip(header + 1)
+        .filter_map(|line| line.split_whitespace().next())
+        .collect();
+    let mut expected: Vec<&str> = PROGRAMS.iter().map(|(name, _)| *name).collect();
+    expected.push("synthetic");
+    assert_eq!(rows, expected);
+}
@@ -0,0 +1,85 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+mod common;
+
+use common::output_of;
+use std::cell::RefCell;
+use std::rc::Rc;
+use ruistic::{Interpreter, Parser, Resolver, Scanner};
+
+/// Runs `src` with `--promote`, so integer arithmetic stays exact.
+fn promoted_output_of(src: &str) -> String {
+    let output = Rc::new(RefCell::new(Vec::new()));
+    let mut interpreter = Interpreter::with_output(output.clone());
+    interpreter.set_promote(true);
+    let statements = Parser::new(Scanner::new(src.to_string()).try_scan_tokens().unwrap()).try_parse().unwrap();
+    Resolver::new().resolve(&statements).unwrap();
+    interpreter.try_interpret(statements).unwrap();
+    String::from_utf8(output.borrow().clone()).unwrap()
+}
+
+const FACTORIAL: &str = "
+    fun factorial(n) {
+        var result = 1;
+        for (i in 2..=n) result = result * i;
+        return result;
+    }
+";
+
+#[test]
+fn prints_a_big_literal_exactly() {
+    assert_eq!(output_of("print 123456789012345678901234567890;"), "123456789012345678901234567890\n");
+    assert_eq!(output_of("print -123456789012345678901234567890;"), "-123456789012345678901234567890\n");
+}
+
+#[test]
+fn keeps_arithmetic_on_big_literals_exact() {
+    let src = "
+        var big = 123456789012345678901234567890;
+        print big + 1;
+        print big - big;
+        print big * 1000;
+        print 2 * 9223372036854775808;
+    ";
+    assert_eq!(output_of(src), "123456789012345678901234567891\n0\n123456789012345678901234567890000\n18446744073709551616\n");
+}
+
+#[test]
+fn returns_to_a_number_when_the_result_fits() {
+    let src = "var difference = 100000000000000000000 - 99999999999999999992; print type(difference); print difference;";
+    assert_eq!(output_of(src), "number\n8\n");
+}
+
+#[test]
+fn computes_a_large_factorial_in_promote_mode() {
+    assert_eq!(promoted_output_of(&format!("{} print factorial(25); print factorial(30);", FACTORIAL)),
+               "15511210043330985984000000\n265252859812191058636308480000000\n");
+}
+
+#[test]
+fn rounds_a_large_factorial_without_promote_mode() {
+    assert_eq!(output_of(&format!("{} print factorial(30) == 265252859812191058636308480000000;", FACTORIAL)), "false\n");
+}
+
+#[test]
+fn equals_a_number_holding_the_same_integer() {
+    assert_eq!(output_of("print 10000000000000000000000 == 1e22;"), "true\n");
+    assert_eq!(output_of("print 1e22 != 10000000000000000000000;"), "false\n");
+    assert_eq!(output_of("print 10000000000000000000001 == 1e22;"), "false\n");
+    assert_eq!(output_of("print [10000000000000000000000] == [1e22];"), "true\n");
+}
+
+#[test]
+fn orders_against_numbers() {
+    let src = "
+        var big = 10000000000000000000001;
+        print big > 1e22;
+        print 1e22 < big;
+        print 10000000000000000000000 <= 1e22;
+        print big > 1.5;
+        print -big < -1e22;
+    ";
+    assert_eq!(output_of(src), "true\ntrue\ntrue\ntrue\ntrue\n");
+}
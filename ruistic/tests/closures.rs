@@ -0,0 +1,44 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+mod common;
+
+use common::output_of;
+
+#[test]
+fn counters_capture_independent_variables() {
+    let src = "
+        fun make_counter() {
+            var count = 0;
+            fun increment() {
+                count = count + 1;
+                return count;
+            }
+            return increment;
+        }
+        var a = make_counter();
+        var b = make_counter();
+        print a();
+        print a();
+        print b();
+        print a();
+    ";
+    assert_eq!(output_of(src), "1\n2\n1\n3\n");
+}
+
+#[test]
+fn closures_see_the_variable_in_scope_where_they_were_written() {
+    let src = "
+        var a = \"global\";
+        {
+            fun show() {
+                print a;
+            }
+            show();
+            var a = \"block\";
+            show();
+        }
+    ";
+    assert_eq!(output_of(src), "global\nglobal\n");
+}
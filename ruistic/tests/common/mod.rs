@@ -0,0 +1,24 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+//! Helpers shared by the integration tests. Each test file compiles this
+//! module on its own and uses only some of it.
+#![allow(dead_code)]
+
+use ruistic::{try_run, Error};
+
+/// What `src` printed, asserting that it ran without errors.
+pub fn output_of(src: &str) -> String {
+    let result = try_run(src);
+    assert_eq!(result.errors, vec![]);
+    result.stdout
+}
+
+/// The message of the one runtime error `src` stops with.
+pub fn runtime_error(src: &str) -> String {
+    match try_run(src).errors.as_slice() {
+        [Error::Runtime { message, .. }] => message.clone(),
+        errors => panic!("expected one runtime error, got {:?}", errors),
+    }
+}
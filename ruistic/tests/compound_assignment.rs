@@ -0,0 +1,52 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+mod common;
+
+use common::output_of;
+use ruistic::{try_run, Error};
+
+#[test]
+fn adds() {
+    assert_eq!(output_of("var x = 5; x += 3; print x;"), "8\n");
+    assert_eq!(output_of("var s = \"ab\"; s += \"cd\"; print s;"), "abcd\n");
+}
+
+#[test]
+fn subtracts() {
+    assert_eq!(output_of("var x = 5; x -= 3; print x;"), "2\n");
+}
+
+#[test]
+fn multiplies() {
+    assert_eq!(output_of("var x = 5; x *= 3; print x;"), "15\n");
+}
+
+#[test]
+fn divides() {
+    assert_eq!(output_of("var x = 6; x /= 3; print x;"), "2\n");
+}
+
+#[test]
+fn groups_the_whole_right_hand_side() {
+    assert_eq!(output_of("var x = 10; x -= 1 + 2; print x;"), "7\n");
+    assert_eq!(output_of("var x = 2; x *= 1 + 2; print x;"), "6\n");
+    assert_eq!(output_of("var x = 12; x /= 2 * 3; print x;"), "2\n");
+}
+
+#[test]
+fn is_an_expression_with_the_new_value() {
+    assert_eq!(output_of("var x = 1; print x += 1; print x;"), "2\n2\n");
+}
+
+#[test]
+fn assigns_to_the_variable_in_scope() {
+    assert_eq!(output_of("var total = 0; { var x = 4; for (i in 0..3) total += x; } print total;"), "12\n");
+}
+
+#[test]
+fn rejects_a_target_that_is_not_a_variable() {
+    let errors = try_run("var x = 1; (x) += 1;").errors;
+    assert!(matches!(&errors[..], [Error::Parse { message, .. }] if message == "Invalid assignment target"));
+}
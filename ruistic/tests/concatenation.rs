@@ -0,0 +1,41 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+mod common;
+
+use common::output_of;
+
+#[test]
+fn builds_a_string_in_a_loop() {
+    let src = "var letters = chars(\"abcdef\"); var s = \"\";
+               for (var i = 0; i < len(letters); i = i + 1) { s = s + letters[i]; s += \"-\"; }
+               print s;";
+    assert_eq!(output_of(src), "a-b-c-d-e-f-\n");
+}
+
+#[test]
+fn appends_in_a_local_and_an_enclosing_scope() {
+    let src = "fun build() { var s = \"a\"; fun more() { s = s + \"b\"; } more(); more(); s = s + \"c\"; return s; }
+               print build();";
+    assert_eq!(output_of(src), "abbc\n");
+}
+
+#[test]
+fn leaves_other_copies_of_the_string_alone() {
+    let src = "var s = \"ab\"; var t = s; s = s + \"c\"; t += \"d\"; print s; print t;";
+    assert_eq!(output_of(src), "abc\nabd\n");
+}
+
+#[test]
+fn appends_to_the_value_read_before_the_right_side_runs() {
+    assert_eq!(output_of("var s = \"ab\"; s = s + s; print s;"), "abab\n");
+    let src = "var s = \"ab\"; fun f() { s = \"z\"; return \"!\"; } s = s + f(); print s;";
+    assert_eq!(output_of(src), "ab!\n");
+}
+
+#[test]
+fn builds_a_long_string() {
+    let src = "var s = \"\"; for (var i = 0; i < 100000; i = i + 1) { s = s + \"x\"; } print len(s);";
+    assert_eq!(output_of(src), "100000\n");
+}
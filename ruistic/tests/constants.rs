@@ -0,0 +1,43 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+mod common;
+
+use common::{output_of, runtime_error};
+use ruistic::{try_run, Error};
+
+#[test]
+fn reads_a_constant() {
+    assert_eq!(output_of("const x = 1 + 2; print x * 2;"), "6\n");
+    assert_eq!(output_of("{ const x = \"local\"; print x; }"), "local\n");
+}
+
+#[test]
+fn rejects_assignment() {
+    assert_eq!(runtime_error("const x = 1; x = 2;"), "Cannot assign to constant 'x'.");
+    assert_eq!(runtime_error("{ const x = 1; x = 2; }"), "Cannot assign to constant 'x'.");
+    assert_eq!(runtime_error("const x = 1; x += 2;"), "Cannot assign to constant 'x'.");
+}
+
+#[test]
+fn rejects_assignment_from_nested_scopes() {
+    assert_eq!(runtime_error("const x = 1; { { x = 2; } }"), "Cannot assign to constant 'x'.");
+    assert_eq!(runtime_error("{ const x = 1; fun f() { x = 2; } f(); }"), "Cannot assign to constant 'x'.");
+}
+
+#[test]
+fn lets_an_inner_variable_shadow_a_constant() {
+    assert_eq!(output_of("const x = 1; { var x = 2; x = 3; print x; } print x;"), "3\n1\n");
+}
+
+#[test]
+fn lets_a_global_constant_be_redeclared_as_a_variable() {
+    assert_eq!(output_of("const x = 1; var x = 2; x = 3; print x;"), "3\n");
+}
+
+#[test]
+fn requires_a_value() {
+    let errors = try_run("const x;").errors;
+    assert!(matches!(errors.first(), Some(Error::Parse { .. })), "{:?}", errors);
+}
@@ -0,0 +1,79 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use ruistic::{Error, Interpreter, Parser, Resolver, Scanner};
+
+/// What `src` printed, and its runtime error, run with currying on or off.
+fn run(src: &str, curry: bool) -> (String, Option<String>) {
+    let statements = Parser::new(Scanner::new(src.to_string()).try_scan_tokens().unwrap()).try_parse().unwrap();
+    Resolver::new().resolve(&statements).unwrap();
+    let output = Rc::new(RefCell::new(Vec::new()));
+    let mut interpreter = Interpreter::with_output(output.clone());
+    interpreter.set_curry(curry);
+    let error = interpreter.try_interpret(statements).err().map(|error| match error {
+        Error::Runtime { message, .. } => message,
+        other => panic!("expected a runtime error, got {:?}", other),
+    });
+    (String::from_utf8(output.take()).unwrap(), error)
+}
+
+fn output_of(src: &str) -> String {
+    let (output, error) = run(src, true);
+    assert_eq!(error, None);
+    output
+}
+
+const ADD: &str = "fun add(a, b) { return a + b; } fun add3(a, b, c) { return a + b + c; }";
+
+#[test]
+fn partial_application_equals_a_full_call() {
+    assert_eq!(output_of(&format!("{} print add(1)(2); print add(1)(2) == add(1, 2);", ADD)), "3\ntrue\n");
+}
+
+#[test]
+fn partial_applications_chain_and_share_nothing() {
+    let src = format!("{} var inc = add(1); var add10 = add3(4)(6); print inc(1), inc(41), add10(5), add3(1)(2)(3);", ADD);
+    assert_eq!(output_of(&src), "2 42 15 6\n");
+}
+
+#[test]
+fn a_call_without_arguments_is_still_a_call() {
+    assert_eq!(output_of("fun f() { return 1; } print f();"), "1\n");
+    assert_eq!(output_of(&format!("{} print type(add());", ADD)), "function\n");
+}
+
+#[test]
+fn over_application_is_an_error() {
+    assert_eq!(run(&format!("{} add(1, 2, 3);", ADD), true).1.as_deref(), Some("Expected 2 arguments but got 3"));
+    assert_eq!(run(&format!("{} add(1)(2, 3);", ADD), true).1.as_deref(), Some("Expected 1 arguments but got 2"));
+}
+
+#[test]
+fn bound_methods_curry() {
+    let src = "
+        class Point {
+            offset(dx, dy) { return this.x + dx + dy; }
+        }
+        var p = Point();
+        p.x = 10;
+        var right = p.offset(5);
+        print right(1);
+        p.x = 20;
+        print right(1);
+    ";
+    assert_eq!(output_of(src), "16\n26\n");
+}
+
+#[test]
+fn missing_arguments_are_an_error_without_curry() {
+    assert_eq!(run(&format!("{} add(1);", ADD), false).1.as_deref(), Some("Expected 2 arguments but got 1"));
+    assert_eq!(run(&format!("{} add(1, 2, 3);", ADD), false).1.as_deref(), Some("Expected 2 arguments but got 3"));
+}
+
+#[test]
+fn natives_do_not_curry() {
+    assert_eq!(run("pow(2);", true).1.as_deref(), Some("Expected 2 arguments but got 1"));
+}
@@ -0,0 +1,40 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+mod common;
+
+use common::output_of;
+use ruistic::try_run;
+
+#[test]
+fn runs_the_body_once_when_the_condition_starts_false() {
+    assert_eq!(output_of("do { print \"once\"; } while (false);"), "once\n");
+}
+
+#[test]
+fn repeats_while_the_condition_holds() {
+    assert_eq!(output_of("var i = 0; do { print i; i += 1; } while (i < 3);"), "0\n1\n2\n");
+}
+
+#[test]
+fn takes_any_statement_as_its_body() {
+    assert_eq!(output_of("var i = 0; do i += 1; while (i < 5); print i;"), "5\n");
+}
+
+#[test]
+fn breaks_out_of_the_loop() {
+    assert_eq!(output_of("var i = 0; do { if (i == 2) break; print i; i += 1; } while (true);"), "0\n1\n");
+}
+
+#[test]
+fn continues_to_the_condition() {
+    let src = "var i = 0; do { i += 1; if (i == 2) continue; print i; } while (i < 4);";
+    assert_eq!(output_of(src), "1\n3\n4\n");
+}
+
+#[test]
+fn requires_the_trailing_semicolon() {
+    let errors = try_run("do print 1; while (false) print 2;").errors;
+    assert_eq!(errors.first().map(|error| error.message()), Some("Expect ';' after 'do' loop."));
+}
@@ -0,0 +1,70 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+use ruistic::{Parser, Scanner};
+
+fn expression(src: &str) -> String {
+    let tokens = Scanner::new(src.to_string()).try_scan_tokens().unwrap();
+    Parser::new(tokens).parse_expression().unwrap().to_string()
+}
+
+fn statements(src: &str) -> Vec<String> {
+    let tokens = Scanner::new(src.to_string()).try_scan_tokens().unwrap();
+    Parser::new(tokens).try_parse().unwrap().iter().map(ToString::to_string).collect()
+}
+
+#[test]
+fn shows_precedence() {
+    assert_eq!(expression("1 + 2 * 3"), "(+ 1 (* 2 3))");
+    assert_eq!(expression("(1 + 2) * 3"), "(* (group (+ 1 2)) 3)");
+    assert_eq!(expression("-a - b or c and d"), "(or (- (- a) b) (and c d))");
+    assert_eq!(expression("a ? b : c ? d : e"), "(?: a b (?: c d e))");
+}
+
+#[test]
+fn shows_every_expression() {
+    assert_eq!(expression("\"hi\" == nil"), "(== \"hi\" nil)");
+    assert_eq!(expression("f(1, [2, 3])[0]"), "([] (call f 1 (list 2 3)) 0)");
+    assert_eq!(expression("a.b.c = x[1] = 2"), "(= (. (. a b) c) (= ([] x 1) 2))");
+    assert_eq!(expression("a += 1"), "(= a (+ a 1))");
+    assert_eq!(expression("loop { break a; }"), "(loop (block (break a)))");
+}
+
+#[test]
+fn shows_every_statement() {
+    let src = "var a; var b = 1; print a, b; write a;
+        fun f(x, y) { return; } class C { m() { return this; } }
+        if (a) b; else { c; }
+        while (true) { if (a) continue; break; }
+        for (var i = 0; i < 2; i = i + 1) {}
+        for (i in 0..=3) {}";
+    assert_eq!(statements(src), [
+        "(var a)",
+        "(var b 1)",
+        "(print a b)",
+        "(write a)",
+        "(fun f (x y) (return))",
+        "(class C (fun m () (return this)))",
+        "(if a (expr b) (block (expr c)))",
+        "(while true (block (if a (continue)) (break)))",
+        "(block (var i 0) (while (< i 2) (block) (= i (+ i 1))))",
+        "(for i (..= 0 3) (block))",
+    ]);
+}
+
+#[test]
+fn dumps_a_script_without_running_it() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_ruistic"))
+        .args(["--dump-ast", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(b"print 1 + 2 * 3;\nprint -nil;").unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "(print (+ 1 (* 2 3)))\n(print (- nil))\n");
+}
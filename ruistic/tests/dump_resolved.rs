@@ -0,0 +1,74 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+use ruistic::{Parser, Resolver, Scanner};
+
+fn dump(src: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_ruistic"))
+        .args(["--dump-resolved", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(src.as_bytes()).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn closure_captured_variable_resolves_one_scope_out() {
+    let src = "\
+fun makeCounter() {
+  var count = 0;
+  fun increment() {
+    count = count + 1;
+    return count;
+  }
+  return increment;
+}
+print makeCounter()();
+";
+    let output = dump(src);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "\
+count at line 4, column 13: depth 1
+count at line 4, column 5: depth 1
+count at line 5, column 12: depth 1
+increment at line 7, column 10: depth 0
+makeCounter at line 9, column 7: global
+");
+}
+
+#[test]
+fn block_locals_resolve_by_nesting() {
+    let output = dump("var a = 1;\n{ var b = a; { print a + b; } }");
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "\
+a at line 2, column 11: global
+a at line 2, column 22: global
+b at line 2, column 26: depth 1
+");
+}
+
+#[test]
+fn reports_resolution_errors_instead() {
+    let output = dump("{ var a = a; }");
+    assert_eq!(output.status.code(), Some(65));
+    assert!(output.stdout.is_empty());
+}
+
+#[test]
+fn resolver_reports_only_when_asked() {
+    let statements = Parser::new(Scanner::new("var a; { print a; }".to_string()).try_scan_tokens().unwrap())
+        .try_parse()
+        .unwrap();
+    let mut resolver = Resolver::new();
+    resolver.resolve(&statements).unwrap();
+    assert!(resolver.report().is_empty());
+
+    let mut resolver = Resolver::new().with_report();
+    resolver.resolve(&statements).unwrap();
+    assert_eq!(resolver.report(), ["a at line 1, column 16: global"]);
+}
@@ -0,0 +1,78 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+mod common;
+
+use common::output_of;
+
+#[test]
+fn nil_equals_nil() {
+    assert_eq!(output_of("print nil == nil; print nil != nil;"), "true\nfalse\n");
+}
+
+#[test]
+fn nil_equals_no_other_value() {
+    let src = "
+        class C {}
+        fun f() {}
+        print nil == 0;
+        print nil == false;
+        print nil == \"\";
+        print nil == \"nil\";
+        print nil == 123456789012345678901234567890;
+        print nil == f;
+        print nil == C;
+        print nil == C();
+    ";
+    assert_eq!(output_of(src), "false\n".repeat(8));
+}
+
+#[test]
+fn nil_is_unequal_to_other_values() {
+    assert_eq!(output_of("print nil != \"x\"; print 0 != nil; print false != nil;"), "true\ntrue\ntrue\n");
+}
+
+#[test]
+fn numbers_are_equal_whatever_the_literal_form() {
+    assert_eq!(output_of("print 1 == 1.0; print 1.0 == 1; print 1e0 == 1; print 0x10 == 16.0; print 1_000 == 1e3;"),
+               "true\ntrue\ntrue\ntrue\ntrue\n");
+    assert_eq!(output_of("print 1 != 1.0; print 1 == 1.5; print -0 == 0.0;"), "false\nfalse\ntrue\n");
+}
+
+#[test]
+fn numbers_order_whatever_the_literal_form() {
+    assert_eq!(output_of("print 2 < 2.5; print 2.5 > 2; print 2 <= 2.0; print 2.0 >= 2; print 3 < 2.5;"),
+               "true\ntrue\ntrue\ntrue\nfalse\n");
+}
+
+#[test]
+fn big_integers_compare_with_numbers_by_value() {
+    let src = "
+        var big = 10000000000000000000000;
+        print big == 1e22;
+        print 1e22 == big;
+        print big < 1e22 * 2;
+        print big > 2.5;
+        print big + 1 > 1e22;
+        print 1 == 123456789012345678901234567890;
+    ";
+    assert_eq!(output_of(src), "true\ntrue\ntrue\ntrue\ntrue\nfalse\n");
+}
+
+#[test]
+fn strings_order_lexicographically() {
+    assert_eq!(output_of("print \"a\" < \"b\"; print \"abc\" < \"abd\"; print \"b\" > \"abc\"; print \"b\" <= \"a\";"),
+               "true\ntrue\ntrue\nfalse\n");
+}
+
+#[test]
+fn a_prefix_orders_before_the_longer_string() {
+    assert_eq!(output_of("print \"ab\" < \"abc\"; print \"\" < \"a\"; print \"b\" >= \"b\"; print \"b\" <= \"b\";"),
+               "true\ntrue\ntrue\ntrue\n");
+}
+
+#[test]
+fn strings_order_by_code_point_not_by_case() {
+    assert_eq!(output_of("print \"B\" < \"a\"; print \"Z\" < \"a\"; print \"é\" > \"z\";"), "true\ntrue\ntrue\n");
+}
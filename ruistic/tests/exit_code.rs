@@ -0,0 +1,98 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+use std::fs;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+fn exit_code(name: &str, src: &str) -> Option<i32> {
+    let path = std::env::temp_dir().join(format!("ruistic-exit-code-{}-{}.ru", name, std::process::id()));
+    fs::write(&path, src).unwrap();
+    let status = Command::new(env!("CARGO_BIN_EXE_ruistic")).arg(&path).output().unwrap().status;
+    fs::remove_file(&path).unwrap();
+    status.code()
+}
+
+#[test]
+fn succeeds_with_zero() {
+    assert_eq!(exit_code("ok", "print 1;"), Some(0));
+}
+
+#[test]
+fn fails_with_65_on_a_syntax_error() {
+    assert_eq!(exit_code("syntax", "print (1;"), Some(65));
+}
+
+#[test]
+fn fails_with_65_on_a_scan_error() {
+    assert_eq!(exit_code("scan", "print 1 # 2;"), Some(65));
+}
+
+#[test]
+fn fails_with_70_on_a_runtime_error() {
+    assert_eq!(exit_code("runtime", "print -nil;"), Some(70));
+}
+
+#[test]
+fn fails_with_66_and_a_message_on_a_missing_file() {
+    let path = std::env::temp_dir().join(format!("ruistic-missing-{}.ru", std::process::id()));
+    let output = Command::new(env!("CARGO_BIN_EXE_ruistic")).arg(&path).output().unwrap();
+    assert_eq!(output.status.code(), Some(66));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.starts_with(&format!("Could not open file '{}': ", path.display())), "{}", stderr);
+    assert!(!stderr.contains("panicked"), "{}", stderr);
+}
+
+#[test]
+fn runs_a_script_from_stdin_like_a_file() {
+    let src = "var a = 1;\nprint a + 1;\nprint -nil;\n";
+    let path = std::env::temp_dir().join(format!("ruistic-stdin-{}.ru", std::process::id()));
+    fs::write(&path, src).unwrap();
+    let from_file = Command::new(env!("CARGO_BIN_EXE_ruistic")).arg(&path).output().unwrap();
+    fs::remove_file(&path).unwrap();
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_ruistic"))
+        .arg("-")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(src.as_bytes()).unwrap();
+    let from_stdin = child.wait_with_output().unwrap();
+
+    assert_eq!(from_stdin.stdout, b"2\n");
+    assert_eq!(from_stdin.stdout, from_file.stdout);
+    assert_eq!(from_stdin.stderr, from_file.stderr);
+    assert_eq!(from_stdin.status.code(), Some(70));
+}
+
+#[test]
+fn prints_scanner_warnings_and_still_runs() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_ruistic"))
+        .arg("-")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(b"print 9007199254740993;").unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(output.stdout, b"9007199254740992\n");
+    assert_eq!(String::from_utf8(output.stderr).unwrap(),
+               "Warning: integer literal '9007199254740993' at line 1 cannot be represented exactly; \
+                it is rounded to 9007199254740992\n");
+}
+
+#[test]
+fn fails_with_65_and_a_message_over_the_token_limit() {
+    let path = std::env::temp_dir().join(format!("ruistic-max-tokens-{}.ru", std::process::id()));
+    fs::write(&path, "print 1;\nprint 2;").unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_ruistic")).arg("--max-tokens=3").arg(&path).output().unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(output.status.code(), Some(65));
+    assert_eq!(output.stdout, b"");
+    assert_eq!(String::from_utf8(output.stderr).unwrap(), "Source exceeds the limit of 3 tokens at line 2\n");
+}
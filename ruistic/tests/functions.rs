@@ -0,0 +1,78 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+mod common;
+
+use common::output_of;
+use ruistic::{try_run, Error};
+
+fn parse_error(src: &str) -> String {
+    match try_run(src).errors.as_slice() {
+        [Error::Parse { message, .. }] => message.clone(),
+        errors => panic!("expected one parse error, got {:?}", errors),
+    }
+}
+
+/// `p0, p1, ...`, `count` names long.
+fn parameters(count: usize) -> String {
+    (0..count).map(|i| format!("p{}", i)).collect::<Vec<_>>().join(", ")
+}
+
+/// `0, 1, ...`, `count` numbers long.
+fn arguments(count: usize) -> String {
+    (0..count).map(|i| i.to_string()).collect::<Vec<_>>().join(", ")
+}
+
+#[test]
+fn an_unset_local_reads_as_nil() {
+    assert_eq!(output_of("fun f() { var x; print x; } f();"), "nil\n");
+}
+
+#[test]
+fn an_unset_local_in_a_nested_block_reads_as_nil() {
+    let src = "
+        fun f(a) {
+            var x;
+            if (a) { var y; print y; }
+            return x;
+        }
+        print f(true);
+    ";
+    assert_eq!(output_of(src), "nil\nnil\n");
+}
+
+#[test]
+fn an_unset_local_is_nil_on_every_call() {
+    let src = "
+        fun f(n) {
+            var x;
+            print x;
+            x = n;
+        }
+        f(1);
+        f(2);
+    ";
+    assert_eq!(output_of(src), "nil\nnil\n");
+}
+
+#[test]
+fn a_function_without_a_return_value_returns_nil() {
+    assert_eq!(output_of("fun f() {} fun g() { return; } print f(); print g();"), "nil\nnil\n");
+}
+
+#[test]
+fn takes_up_to_255_parameters_and_arguments() {
+    let src = format!("fun f({}) {{ return p0 + p254; }} print f({});", parameters(255), arguments(255));
+    assert_eq!(output_of(&src), "254\n");
+}
+
+#[test]
+fn rejects_a_256th_parameter() {
+    assert_eq!(parse_error(&format!("fun f({}) {{}}", parameters(256))), "Can't have more than 255 parameters");
+}
+
+#[test]
+fn rejects_a_256th_argument() {
+    assert_eq!(parse_error(&format!("fun f() {{}} f({});", arguments(256))), "Can't have more than 255 arguments");
+}
@@ -0,0 +1,49 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+use ruistic::History;
+
+#[test]
+fn starts_empty() {
+    let history = History::new();
+    assert!(history.entries().is_empty());
+    assert_eq!(history.to_string(), "");
+}
+
+#[test]
+fn numbers_entries_from_one() {
+    let mut history = History::new();
+    history.push("var a = 1;");
+    history.push("a + 1");
+    assert_eq!(history.entries(), ["var a = 1;", "a + 1"]);
+    assert_eq!(history.to_string(), "   1  var a = 1;\n   2  a + 1\n");
+}
+
+#[test]
+fn leaves_out_empty_inputs() {
+    let mut history = History::new();
+    history.push("");
+    history.push("print 1;");
+    history.push("");
+    assert_eq!(history.to_string(), "   1  print 1;\n");
+}
+
+#[test]
+fn keeps_a_multi_line_input_as_one_entry() {
+    let mut history = History::new();
+    history.push("{\nprint 1;\n}");
+    history.push("2");
+    assert_eq!(history.to_string(), "   1  {\nprint 1;\n}\n   2  2\n");
+}
+
+#[test]
+fn pads_numbers_to_four_columns() {
+    let mut history = History::new();
+    for i in 0..1000 {
+        history.push(&i.to_string());
+    }
+    let listing = history.to_string();
+    assert!(listing.starts_with("   1  0\n"));
+    assert!(listing.ends_with("1000  999\n"));
+}
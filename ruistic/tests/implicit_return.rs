@@ -0,0 +1,97 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use ruistic::{Error, Interpreter, Parser, Resolver, Scanner, Stmt};
+
+fn parse(src: &str, implicit_return: bool) -> Result<Vec<Stmt>, Vec<Error>> {
+    let mut parser = Parser::new(Scanner::new(src.to_string()).try_scan_tokens().unwrap());
+    parser.set_implicit_return(implicit_return);
+    parser.try_parse()
+}
+
+fn output_of(src: &str, implicit_return: bool) -> String {
+    let statements = parse(src, implicit_return).unwrap();
+    Resolver::new().resolve(&statements).unwrap();
+    let output = Rc::new(RefCell::new(Vec::new()));
+    Interpreter::with_output(output.clone()).interpret(statements);
+    String::from_utf8(output.take()).unwrap()
+}
+
+fn parse_error(src: &str, implicit_return: bool) -> String {
+    match parse(src, implicit_return).unwrap_err().as_slice() {
+        [Error::Parse { message, .. }] => message.clone(),
+        errors => panic!("expected one parse error, got {:?}", errors),
+    }
+}
+
+#[test]
+fn final_expression_is_returned_in_implicit_return_mode() {
+    assert_eq!(output_of("fun f() { 42 } print f();", true), "42\n");
+    assert_eq!(output_of("fun f(a) { var b = a * 2; b + 1 } print f(3);", true), "7\n");
+}
+
+#[test]
+fn final_expression_is_discarded_otherwise() {
+    assert_eq!(output_of("fun f() { 42 } print f();", false), "nil\n");
+}
+
+#[test]
+fn final_expressions_of_tail_blocks_are_returned() {
+    let src = "
+        fun sign(n) {
+            if (n < 0) { -1 } else if (n == 0) { 0 } else { { 1 } }
+        }
+        print sign(-5); print sign(0); print sign(5);
+    ";
+    assert_eq!(output_of(src, true), "-1\n0\n1\n");
+    assert_eq!(output_of("fun f(c) { if (c) { 42 } } print f(true); print f(false);", true), "42\nnil\n");
+}
+
+#[test]
+fn final_expressions_of_tail_switch_arms_are_returned() {
+    let src = "
+        fun name(n) {
+            switch (n) {
+                case 1: \"one\";
+                default: \"many\"
+            }
+        }
+        print name(1); print name(2);
+    ";
+    assert_eq!(output_of(src, true), "nil\nmany\n");
+}
+
+#[test]
+fn tail_blocks_return_nil_otherwise() {
+    assert_eq!(output_of("fun f(c) { if (c) { 42 } else { 0 } } print f(true);", false), "nil\n");
+}
+
+#[test]
+fn a_semicolon_is_still_needed_outside_tail_position() {
+    let message = "Expect ';' after expression; only the final expression of a function may omit it.";
+    for implicit_return in [true, false] {
+        assert_eq!(parse_error("fun f(c) { if (c) { 42 } print 1; }", implicit_return), message);
+        assert_eq!(parse_error("fun f() { while (true) { 42 } }", implicit_return), message);
+        assert_eq!(parse_error("fun f() { var x = loop { 1 }; }", implicit_return), message);
+    }
+}
+
+#[test]
+fn a_semicolon_is_needed_outside_functions() {
+    assert_eq!(parse_error("{ 42 }", true), "Expect ';' after expression.");
+}
+
+#[test]
+fn nested_functions_check_their_own_tails() {
+    let src = "
+        fun outer() {
+            fun inner() { if (true) { 1 } }
+            inner() + 1
+        }
+        print outer();
+    ";
+    assert_eq!(output_of(src, true), "2\n");
+}
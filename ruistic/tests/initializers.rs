@@ -0,0 +1,72 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+mod common;
+
+use common::{output_of, runtime_error};
+use ruistic::{run, Error};
+
+const POINT: &str = "
+    class Point {
+        init(x, y) {
+            this.x = x;
+            this.y = y;
+        }
+        sum() { return this.x + this.y; }
+    }
+";
+
+#[test]
+fn init_sets_fields_from_the_arguments() {
+    assert_eq!(output_of(&format!("{} var p = Point(1, 2); print p.x, p.y, p.sum();", POINT)), "1 2 3\n");
+}
+
+#[test]
+fn a_call_checks_the_arity_of_init() {
+    assert_eq!(runtime_error(&format!("{} Point(1);", POINT)), "Expected 2 arguments but got 1");
+    assert_eq!(runtime_error("class Empty {} Empty(1);"), "Expected 0 arguments but got 1");
+}
+
+#[test]
+fn calling_init_directly_returns_the_instance() {
+    assert_eq!(output_of(&format!("{} var p = Point(1, 2); print p.init(5, 6) == p, p.x;", POINT)), "true 5\n");
+}
+
+#[test]
+fn a_bare_return_in_init_still_returns_the_instance() {
+    let src = "
+        class Sign {
+            init(n) {
+                this.sign = \"positive\";
+                if (n > 0) return;
+                this.sign = \"not positive\";
+            }
+        }
+        print Sign(3).sign, \"/\", Sign(0).sign;
+    ";
+    assert_eq!(output_of(src), "positive / not positive\n");
+}
+
+#[test]
+fn returning_a_value_from_init_is_a_resolve_error() {
+    assert_eq!(run("class A {\n    init() { return 1; }\n}").unwrap_err(), vec![Error::Resolve {
+        message: "Can't return a value from an initializer".to_string(),
+        line: 2,
+        column: 14,
+    }]);
+}
+
+#[test]
+fn a_function_inside_init_may_return_a_value() {
+    let src = "
+        class A {
+            init() {
+                fun double(n) { return n * 2; }
+                this.value = double(4);
+            }
+        }
+        print A().value;
+    ";
+    assert_eq!(output_of(src), "8\n");
+}
@@ -0,0 +1,43 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+#![cfg(feature = "serde")]
+
+use ruistic::{ast_to_json, Parser, Scanner};
+use serde_json::{json, Value};
+
+fn to_json(src: &str) -> Value {
+    let tokens = Scanner::new(src.to_string()).try_scan_tokens().unwrap();
+    let statements = Parser::new(tokens).try_parse().unwrap();
+    serde_json::from_str(&ast_to_json(&statements)).unwrap()
+}
+
+#[test]
+fn tags_each_node_with_its_variant() {
+    let json = to_json("print 1 + 2;");
+    let binary = &json[0]["Print"]["values"][0]["Binary"];
+    assert_eq!(binary["left"], json!({ "Literal": { "Number": 1.0 } }));
+    assert_eq!(binary["right"], json!({ "Literal": { "Number": 2.0 } }));
+    assert_eq!(json[0]["Print"]["newline"], json!(true));
+}
+
+#[test]
+fn includes_the_tokens() {
+    let json = to_json("var a;\na = \"hi\";");
+    let operator = &to_json("print 1 + 2;")[0]["Print"]["values"][0]["Binary"]["operator"];
+    assert_eq!(operator["type"], json!("PLUS"));
+    assert_eq!(operator["lexeme"], json!("+"));
+    assert_eq!(operator["line"], json!(1));
+    assert_eq!(operator["column"], json!(9));
+    let assign = &json[1]["Expr"][0]["Assign"];
+    assert_eq!(assign["name"]["lexeme"], json!("a"));
+    assert_eq!(assign["name"]["line"], json!(2));
+    assert_eq!(assign["value"], json!({ "Literal": { "String": "hi" } }));
+}
+
+#[test]
+fn writes_big_integers_as_digits() {
+    let json = to_json("123456789012345678901234567890;");
+    assert_eq!(json[0]["Expr"][0], json!({ "Literal": { "BigInt": "123456789012345678901234567890" } }));
+}
@@ -0,0 +1,74 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use ruistic::{Error, Interpreter, Parser, Resolver, Scanner};
+
+/// What `src` printed, and its runtime error, run with lenient nil on or off.
+fn run(src: &str, lenient_nil: bool) -> (String, Option<String>) {
+    let statements = Parser::new(Scanner::new(src.to_string()).try_scan_tokens().unwrap()).try_parse().unwrap();
+    Resolver::new().resolve(&statements).unwrap();
+    let output = Rc::new(RefCell::new(Vec::new()));
+    let mut interpreter = Interpreter::with_output(output.clone());
+    interpreter.set_lenient_nil(lenient_nil);
+    let error = interpreter.try_interpret(statements).err().map(|error| match error {
+        Error::Runtime { message, .. } => message,
+        other => panic!("expected a runtime error, got {:?}", other),
+    });
+    (String::from_utf8(output.take()).unwrap(), error)
+}
+
+fn lenient_output(src: &str) -> String {
+    let (output, error) = run(src, true);
+    assert_eq!(error, None);
+    output
+}
+
+fn strict_error(src: &str) -> String {
+    run(src, false).1.expect("a runtime error")
+}
+
+#[test]
+fn nil_properties_calls_and_indexes_are_nil_when_lenient() {
+    assert_eq!(lenient_output("print nil.foo; print nil.foo(); print nil(); print nil[0];"), "nil\nnil\nnil\nnil\n");
+}
+
+#[test]
+fn chains_through_missing_data_are_nil_when_lenient() {
+    let src = "
+        class Config {}
+        var config = Config();
+        config.server = nil;
+        print config.server.ports[0];
+        print config.server.name().length;
+    ";
+    assert_eq!(lenient_output(src), "nil\nnil\n");
+}
+
+#[test]
+fn index_of_nil_still_evaluates_the_index_when_lenient() {
+    assert_eq!(lenient_output("var i = 0; fun next() { i = i + 1; return i; } print nil[next()]; print i;"), "nil\n1\n");
+    assert_eq!(run("print nil[-nil];", true).1.as_deref(), Some("Operand of '-' must be a number, got nil"));
+}
+
+#[test]
+fn nil_is_an_error_when_strict() {
+    assert_eq!(strict_error("nil.foo;"), "Only instances have properties");
+    assert_eq!(strict_error("nil.foo();"), "Only instances have properties");
+    assert_eq!(strict_error("nil();"), "Can only call functions and classes");
+    assert_eq!(strict_error("nil[0];"), "Only lists can be indexed, got nil");
+}
+
+#[test]
+fn assigning_through_nil_is_an_error_even_when_lenient() {
+    assert_eq!(run("nil.foo = 1;", true).1.as_deref(), Some("Only instances have fields"));
+    assert_eq!(run("nil[0] = 1;", true).1.as_deref(), Some("Only lists can be indexed, got nil"));
+}
+
+#[test]
+fn other_values_are_still_checked_when_lenient() {
+    assert_eq!(run("1[0];", true).1.as_deref(), Some("Only lists can be indexed, got number"));
+    assert_eq!(run("\"s\".foo;", true).1.as_deref(), Some("Only instances have properties"));
+}
@@ -0,0 +1,100 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+mod common;
+
+use common::{output_of, runtime_error};
+
+#[test]
+fn builds_list_literals() {
+    assert_eq!(output_of("print [];"), "[]\n");
+    assert_eq!(output_of("print [1, \"two\", nil, [3, 4]];"), "[1, \"two\", nil, [3, 4]]\n");
+    assert_eq!(output_of("var x = 2; print [x, x * 2, x * 3];"), "[2, 4, 6]\n");
+}
+
+#[test]
+fn reads_by_index() {
+    assert_eq!(output_of("var list = [10, 20, 30]; print list[0]; print list[2];"), "10\n30\n");
+    assert_eq!(output_of("print [[1, 2], [3, 4]][1][0];"), "3\n");
+}
+
+#[test]
+fn writes_by_index() {
+    assert_eq!(output_of("var list = [1, 2, 3]; list[1] = \"x\"; print list;"), "[1, \"x\", 3]\n");
+    assert_eq!(output_of("var list = [1]; print list[0] = 5;"), "5\n");
+}
+
+#[test]
+fn chains_element_assignments() {
+    assert_eq!(output_of("var a = [0]; var b = [0]; a[0] = b[0] = 5; print a; print b;"), "[5]\n[5]\n");
+    assert_eq!(output_of("var a = [0]; var b = [0]; print a[0] = b[0] = 5;"), "5\n");
+}
+
+#[test]
+fn shares_a_list_between_variables() {
+    assert_eq!(output_of("var a = [1, 2]; var b = a; b[0] = 9; print a;"), "[9, 2]\n");
+}
+
+#[test]
+fn rejects_a_negative_index() {
+    assert_eq!(runtime_error("var list = [1, 2, 3]; print list[-1];"),
+               "List index -1 is out of bounds for a list of length 3");
+}
+
+#[test]
+fn rejects_an_index_past_the_end() {
+    assert_eq!(runtime_error("var list = [1, 2, 3]; print list[3];"),
+               "List index 3 is out of bounds for a list of length 3");
+    assert_eq!(runtime_error("var list = []; list[0] = 1;"),
+               "List index 0 is out of bounds for a list of length 0");
+}
+
+#[test]
+fn rejects_a_non_integer_index() {
+    assert_eq!(runtime_error("print [1, 2][0.5];"), "List index must be an integer, got 0.5");
+    assert_eq!(runtime_error("print [1, 2][\"0\"];"), "List index must be a number, got string");
+}
+
+#[test]
+fn rejects_indexing_a_non_list() {
+    assert_eq!(runtime_error("var s = \"abc\"; print s[0];"), "Only lists can be indexed, got string");
+}
+
+#[test]
+fn freeze_rejects_writes_to_a_list_and_its_nested_lists() {
+    assert_eq!(runtime_error("var a = freeze([1, 2]); a[0] = 3;"), "Can't modify a frozen list");
+    assert_eq!(runtime_error("var a = [1, [2]]; freeze(a); a[1][0] = 3;"), "Can't modify a frozen list");
+    assert_eq!(runtime_error("var a = [1]; var b = a; freeze(a); b[0] = 2;"), "Can't modify a frozen list");
+}
+
+#[test]
+fn freeze_leaves_copies_writable() {
+    let src = "var a = freeze([1, [2]]); var b = [a[0], 3]; b[0] = 5; print a; print b;";
+    assert_eq!(output_of(src), "[1, [2]]\n[5, 3]\n");
+}
+
+#[test]
+fn freeze_handles_a_list_that_contains_itself() {
+    assert_eq!(runtime_error("var a = [1, 2]; a[1] = a; freeze(a); a[0] = 3;"), "Can't modify a frozen list");
+}
+
+#[test]
+fn freeze_rejects_other_types() {
+    assert_eq!(runtime_error("freeze(1);"), "freeze() expects a list, got number");
+}
+
+#[test]
+fn prints_a_list_that_contains_itself() {
+    assert_eq!(output_of("var a = [1]; a[0] = a; print a;"), "[[...]]\n");
+    assert_eq!(output_of("var a = [1, \"x\"]; var b = [a]; a[0] = b; print a; print b;"), "[[[...]], \"x\"]\n[[[...], \"x\"]]\n");
+    assert_eq!(output_of("var a = [1, 2]; print [a, a];"), "[[1, 2], [1, 2]]\n");
+}
+
+#[test]
+fn compares_lists_that_contain_themselves() {
+    assert_eq!(output_of("var a = [1]; a[0] = a; print a == a;"), "true\n");
+    assert_eq!(output_of("var a = [1]; a[0] = a; var b = [1]; b[0] = b; print a == b;"), "true\n");
+    assert_eq!(output_of("var a = [1, 2]; a[0] = a; var b = [1, 3]; b[0] = b; print a == b;"), "false\n");
+    assert_eq!(output_of("var a = [1]; a[0] = a; print a == [a]; print a == [1];"), "true\nfalse\n");
+}
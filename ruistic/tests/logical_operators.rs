@@ -0,0 +1,47 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+mod common;
+
+use common::output_of;
+use ruistic::{try_run, Error, Parser, Scanner};
+
+fn ast(src: &str) -> String {
+    let tokens = Scanner::new(src.to_string()).try_scan_tokens().unwrap();
+    Parser::new(tokens).parse_expression().unwrap().to_string()
+}
+
+#[test]
+fn symbols_parse_like_the_keywords() {
+    assert_eq!(ast("a && b"), ast("a and b"));
+    assert_eq!(ast("a || b"), ast("a or b"));
+    assert_eq!(ast("a || b && c"), "(or a (and b c))");
+}
+
+#[test]
+fn symbols_evaluate_like_the_keywords() {
+    assert_eq!(output_of("print 1 && 2; print nil && 2; print 1 || 2; print nil || false;"),
+               output_of("print 1 and 2; print nil and 2; print 1 or 2; print nil or false;"));
+}
+
+#[test]
+fn yield_the_deciding_operand_and_short_circuit() {
+    let src = "
+        fun loud(value) { print \"evaluated\"; return value; }
+        print nil and loud(1);
+        print \"yes\" or loud(2);
+        print false or loud(3);
+    ";
+    assert_eq!(output_of(src), "nil\nyes\nevaluated\n3\n");
+}
+
+#[test]
+fn rejects_a_single_ampersand_or_bar() {
+    for src in ["print 1 & 2;", "print 1 | 2;"] {
+        match &try_run(src).errors[..] {
+            [Error::Scan { message, .. }] => assert!(message.starts_with("Unrecognized character"), "{}", message),
+            errors => panic!("expected one scan error, got {:?}", errors),
+        }
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+mod common;
+
+use common::output_of;
+
+#[test]
+fn break_gives_the_loop_its_value() {
+    assert_eq!(output_of("var x = loop { break 7; }; print x;"), "7\n");
+    assert_eq!(output_of("print loop { break; };"), "nil\n");
+}
+
+#[test]
+fn loop_runs_until_break() {
+    let src = "
+        var i = 0;
+        var found = loop {
+            i = i + 1;
+            if (i * i > 50) break i;
+        };
+        print found;
+    ";
+    assert_eq!(output_of(src), "8\n");
+}
+
+#[test]
+fn break_leaves_only_the_innermost_loop() {
+    let src = "
+        var i = 0;
+        var pairs = loop {
+            i = i + 1;
+            var j = loop { break i * 10; };
+            while (true) { break; }
+            if (i == 3) break [i, j];
+        };
+        print pairs;
+    ";
+    assert_eq!(output_of(src), "[3, 30]\n");
+}
+
+#[test]
+fn return_leaves_the_loop_and_the_function() {
+    assert_eq!(output_of("fun f() { loop { return 1; } } print f();"), "1\n");
+    let src = "
+        fun find(list, x) {
+            var i = 0;
+            var unused = loop {
+                if (i == len(list)) return -1;
+                if (list[i] == x) return i;
+                i = i + 1;
+            };
+            print \"unreachable\";
+        }
+        print find([4, 5, 6], 6);
+        print find([4, 5, 6], 7);
+    ";
+    assert_eq!(output_of(src), "2\n-1\n");
+}
+
+#[test]
+fn return_unwinds_through_the_enclosing_expression() {
+    let src = "
+        var calls = 0;
+        fun count() { calls = calls + 1; return 1; }
+        fun f() { return count() + loop { loop { return 5; }; } + count(); }
+        print f();
+        print calls;
+    ";
+    assert_eq!(output_of(src), "5\n1\n");
+}
+
+#[test]
+fn continue_skips_to_the_next_iteration() {
+    let src = "
+        var i = 0;
+        var total = 0;
+        print loop {
+            i = i + 1;
+            if (i > 4) break total;
+            if (i == 2) continue;
+            total = total + i;
+        };
+    ";
+    assert_eq!(output_of(src), "8\n");
+}
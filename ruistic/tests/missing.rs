@@ -0,0 +1,59 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+mod common;
+
+use common::{output_of, runtime_error};
+
+#[test]
+fn an_undefined_property_is_given_by_missing() {
+    let src = "
+        class Defaults {
+            __missing__(name) { return \"no \" + name; }
+        }
+        print Defaults().color;
+    ";
+    assert_eq!(output_of(src), "no color\n");
+}
+
+#[test]
+fn an_undefined_method_is_dispatched_through_missing() {
+    let src = "
+        class Proxy {
+            __missing__(name) {
+                fun forward(argument) { return this.prefix + name + \"(\" + argument + \")\"; }
+                return forward;
+            }
+        }
+        var proxy = Proxy();
+        proxy.prefix = \"called \";
+        print proxy.greet(\"world\");
+    ";
+    assert_eq!(output_of(src), "called greet(world)\n");
+}
+
+#[test]
+fn fields_and_methods_come_before_missing() {
+    let src = "
+        class Both {
+            method() { return \"method\"; }
+            __missing__(name) { return \"missing\"; }
+        }
+        var both = Both();
+        both.field = \"field\";
+        print both.field, both.method(), both.other;
+    ";
+    assert_eq!(output_of(src), "field method missing\n");
+}
+
+#[test]
+fn without_missing_an_undefined_property_is_an_error() {
+    assert_eq!(runtime_error("class Plain {} print Plain().color;"), "Undefined property 'color'");
+}
+
+#[test]
+fn missing_must_take_the_property_name() {
+    assert_eq!(runtime_error("class Odd { __missing__() { return 1; } } print Odd().color;"),
+               "'__missing__' must take one parameter, the property name");
+}
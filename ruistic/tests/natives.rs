@@ -0,0 +1,354 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+mod common;
+
+use common::{output_of, runtime_error};
+use ruistic::{try_run, Error};
+
+#[test]
+fn len_counts_characters_of_a_string() {
+    assert_eq!(output_of("print len(\"abc\"); print len(\"\"); print len(\"héllo\");"), "3\n0\n5\n");
+}
+
+#[test]
+fn len_counts_elements_of_a_list() {
+    assert_eq!(output_of("print len([1, [2, 3], nil]); print len([]);"), "3\n0\n");
+}
+
+#[test]
+fn len_rejects_other_types() {
+    assert_eq!(try_run("print len(42);").errors, vec![Error::Runtime {
+        message: "len() expects a string or list, got number".to_string(),
+        line: Some(1),
+    }]);
+}
+
+#[test]
+fn natives_check_their_arity() {
+    assert_eq!(runtime_error("len(\"a\", \"b\");"), "Expected 1 arguments but got 2");
+}
+
+#[test]
+fn natives_are_values() {
+    assert_eq!(output_of("var size = len; print size(\"ab\"); print len; print len == len;"),
+               "2\n<native fn len>\ntrue\n");
+}
+
+#[test]
+fn clock_returns_seconds_since_the_epoch() {
+    let output = output_of("var first = clock(); var second = clock(); print first; print second >= first;");
+    let (first, rest) = output.split_once('\n').unwrap();
+    assert!(first.parse::<f64>().unwrap() > 1_600_000_000.0);
+    assert_eq!(rest, "true\n");
+}
+
+#[test]
+fn sqrt_takes_the_square_root() {
+    assert_eq!(output_of("print sqrt(16); print sqrt(2); print sqrt(0);"), "4\n1.4142135623730951\n0\n");
+}
+
+#[test]
+fn sqrt_rejects_a_negative_number() {
+    assert_eq!(runtime_error("print sqrt(-4);"), "sqrt() expects a non-negative number, got -4");
+}
+
+#[test]
+fn abs_drops_the_sign() {
+    assert_eq!(output_of("print abs(-3.5); print abs(2); print abs(-123456789012345678901234567890);"),
+               "3.5\n2\n123456789012345678901234567890\n");
+}
+
+#[test]
+fn floor_and_ceil_round_to_whole_numbers() {
+    assert_eq!(output_of("print floor(2.7); print floor(-2.2); print ceil(2.2); print ceil(-2.7);"), "2\n-3\n3\n-2\n");
+}
+
+#[test]
+fn pow_raises_to_a_power() {
+    assert_eq!(output_of("print pow(2, 10); print pow(9, 0.5); print pow(2, -1);"), "1024\n3\n0.5\n");
+}
+
+#[test]
+fn gcd_and_lcm_of_two_integers() {
+    assert_eq!(output_of("print gcd(12, 8) == 4; print gcd(-12, 18), gcd(7, 0), gcd(0, 0);"), "true\n6 7 0\n");
+    assert_eq!(output_of("print lcm(4, 6), lcm(-3, 5), lcm(0, 9);"), "12 15 0\n");
+}
+
+#[test]
+fn gcd_and_lcm_reject_what_is_not_an_integer() {
+    assert_eq!(runtime_error("gcd(2.5, 5);"), "gcd() expects an integer, got 2.5");
+    assert_eq!(runtime_error("lcm(4, \"6\");"), "lcm() expects a number, got string");
+    assert_eq!(runtime_error("gcd(1e20, 5);"), "gcd() can't take an integer as large as 100000000000000000000");
+}
+
+#[test]
+fn log_defaults_to_the_natural_logarithm() {
+    assert_eq!(output_of("print log(8, 2) == 3; print log(1000, 10), log(9, 3), log(1);"), "true\n3 2 0\n");
+    assert_eq!(output_of("print approxEq(log(pow(2.718281828459045, 2)), 2);"), "true\n");
+}
+
+#[test]
+fn log_rejects_a_non_positive_number_or_base() {
+    assert_eq!(runtime_error("log(0);"), "log() expects a positive number, got 0");
+    assert_eq!(runtime_error("log(-8, 2);"), "log() expects a positive number, got -8");
+    assert_eq!(runtime_error("log(8, 1);"), "log() expects a positive base other than 1, got 1");
+    assert_eq!(runtime_error("log(8, -2);"), "log() expects a positive base other than 1, got -2");
+}
+
+#[test]
+fn math_functions_reject_non_numbers() {
+    assert_eq!(runtime_error("sqrt(\"4\");"), "sqrt() expects a number, got string");
+    assert_eq!(runtime_error("abs(nil);"), "abs() expects a number, got nil");
+    assert_eq!(runtime_error("floor(true);"), "floor() expects a number, got bool");
+    assert_eq!(runtime_error("ceil([1]);"), "ceil() expects a number, got list");
+    assert_eq!(runtime_error("pow(2, \"x\");"), "pow() expects a number, got string");
+}
+
+#[test]
+fn split_breaks_a_string_at_each_separator() {
+    assert_eq!(output_of("var parts = split(\"a,b,c\", \",\"); print parts; print len(parts); print parts[1];"),
+               "[\"a\", \"b\", \"c\"]\n3\nb\n");
+    assert_eq!(output_of("print split(\"a, b,, c\", \", \");"), "[\"a\", \"b,\", \"c\"]\n");
+    assert_eq!(output_of("print len(split(\",a,\", \",\"));"), "3\n");
+}
+
+#[test]
+fn split_without_the_separator_gives_one_part() {
+    assert_eq!(output_of("var parts = split(\"abc\", \";\"); print parts; print len(parts);"), "[\"abc\"]\n1\n");
+}
+
+#[test]
+fn split_on_an_empty_separator_gives_the_characters() {
+    assert_eq!(output_of("print split(\"héllo\", \"\");"), "[\"h\", \"é\", \"l\", \"l\", \"o\"]\n");
+    assert_eq!(output_of("print len(split(\"\", \"\"));"), "0\n");
+}
+
+#[test]
+fn split_rejects_non_strings() {
+    assert_eq!(runtime_error("split(1, \",\");"), "split() expects two strings, got number and string");
+}
+
+#[test]
+fn type_names_every_kind_of_value() {
+    let src = "
+        class Point {}
+        fun f() {}
+        print type(1.5);
+        print type(123456789012345678901234567890);
+        print type(\"text\");
+        print type(true);
+        print type(nil);
+        print type([1, 2]);
+        print type(f);
+        print type(len);
+        print type(Point);
+        print type(Point());
+    ";
+    assert_eq!(output_of(src), "number\nnumber\nstring\nbool\nnil\nlist\nfunction\nfunction\nclass\ninstance\n");
+}
+
+#[test]
+fn first_and_last_return_the_end_elements() {
+    assert_eq!(output_of("var list = [1, 2, 3]; print first(list); print last(list);"), "1\n3\n");
+    assert_eq!(output_of("print first([\"only\"]); print last([\"only\"]);"), "only\nonly\n");
+}
+
+#[test]
+fn first_and_last_reject_an_empty_list() {
+    assert_eq!(runtime_error("first([]);"), "first() of an empty list");
+    assert_eq!(runtime_error("last([]);"), "last() of an empty list");
+    assert_eq!(runtime_error("first(\"abc\");"), "first() expects a list, got string");
+}
+
+#[test]
+fn count_counts_equal_elements() {
+    assert_eq!(output_of("print count([1, 1, 2], 1) == 2; print count([1, 1, 2], 3); print count([], nil);"),
+               "true\n0\n0\n");
+    assert_eq!(output_of("print count([\"a\", 1, \"a\", [1]], \"a\"); print count([[1], [1, 2]], [1]);"), "2\n1\n");
+}
+
+#[test]
+fn frequency_pairs_each_value_with_its_count() {
+    assert_eq!(output_of("print frequency([\"b\", \"a\", \"b\", 1, \"b\"]);"), "[[\"b\", 3], [\"a\", 1], [1, 1]]\n");
+    assert_eq!(output_of("print frequency([]);"), "[]\n");
+}
+
+#[test]
+fn count_and_frequency_reject_a_non_list() {
+    assert_eq!(runtime_error("count(\"aa\", \"a\");"), "count() expects a list, got string");
+    assert_eq!(runtime_error("frequency(nil);"), "frequency() expects a list, got nil");
+}
+
+#[test]
+fn chars_splits_a_string_into_characters() {
+    assert_eq!(output_of("print chars(\"abc\") == [\"a\", \"b\", \"c\"]; print len(chars(\"\"));"), "true\n0\n");
+}
+
+#[test]
+fn chars_keeps_multibyte_characters_whole() {
+    assert_eq!(output_of("var c = chars(\"héllo ✓\"); print len(c); print c[1]; print c[6];"), "7\né\n✓\n");
+    assert_eq!(output_of("print chars(\"日本\") == split(\"日本\", \"\");"), "true\n");
+}
+
+#[test]
+fn chars_rejects_a_non_string() {
+    assert_eq!(runtime_error("chars([\"a\"]);"), "chars() expects a string, got list");
+}
+
+#[test]
+fn code_point_and_from_code_point_convert_characters() {
+    assert_eq!(output_of("print codePoint(\"A\") == 65; print fromCodePoint(66) == \"B\";"), "true\ntrue\n");
+    assert_eq!(output_of("print codePoint(\"é\"); print codePoint(\"✓\"); print fromCodePoint(128512);"), "233\n10003\n😀\n");
+    assert_eq!(output_of("print fromCodePoint(codePoint(\"a\") + 2);"), "c\n");
+}
+
+#[test]
+fn code_point_rejects_anything_but_one_character() {
+    assert_eq!(runtime_error("codePoint(\"ab\");"), "codePoint() expects a one-character string, got \"ab\"");
+    assert_eq!(runtime_error("codePoint(\"\");"), "codePoint() expects a one-character string, got \"\"");
+    assert_eq!(runtime_error("codePoint(65);"), "codePoint() expects a string, got number");
+}
+
+#[test]
+fn from_code_point_rejects_a_value_that_is_not_a_character() {
+    assert_eq!(runtime_error("fromCodePoint(55296);"), "fromCodePoint() expects a Unicode scalar value, got 55296");
+    assert_eq!(runtime_error("fromCodePoint(-1);"), "fromCodePoint() expects a Unicode scalar value, got -1");
+    assert_eq!(runtime_error("fromCodePoint(65.5);"), "fromCodePoint() expects a Unicode scalar value, got 65.5");
+    assert_eq!(runtime_error("fromCodePoint(\"A\");"), "fromCodePoint() expects a number, got string");
+}
+
+#[test]
+fn approx_eq_compares_within_the_default_epsilon() {
+    assert_eq!(output_of("print 0.1 + 0.2 == 0.3; print approxEq(0.1 + 0.2, 0.3); print approxEq(1, 1.001);"),
+               "false\ntrue\nfalse\n");
+}
+
+#[test]
+fn approx_eq_takes_an_optional_epsilon() {
+    assert_eq!(output_of("print approxEq(0.1 + 0.2, 0.3, 1e-20); print approxEq(1, 1.001, 0.01); print approxEq(2, 2, 0);"),
+               "false\ntrue\ntrue\n");
+}
+
+#[test]
+fn approx_eq_checks_its_arguments() {
+    assert_eq!(runtime_error("approxEq(1);"), "Expected 2 to 3 arguments but got 1");
+    assert_eq!(runtime_error("approxEq(1, 2, 3, 4);"), "Expected 2 to 3 arguments but got 4");
+    assert_eq!(runtime_error("approxEq(1, \"1\");"), "approxEq() expects a number, got string");
+    assert_eq!(runtime_error("approxEq(1, 1, -1);"), "approxEq() expects a non-negative epsilon, got -1");
+}
+
+#[test]
+fn to_boolean_follows_truthiness() {
+    assert_eq!(output_of("print toBoolean(nil), toBoolean(false), toBoolean(0), toBoolean(\"\"), toBoolean([]);"),
+               "false false true true true\n");
+}
+
+#[test]
+fn to_int_truncates_numbers_and_parses_strings() {
+    assert_eq!(output_of("print toInt(\"5\") == 5; print toInt(3.9), toInt(-3.9), toInt(\" -12 \"), toInt(\"+7\");"),
+               "true\n3 -3 -12 7\n");
+    assert_eq!(output_of("print toInt(\"123456789012345678901234567890\") == 123456789012345678901234567890;"),
+               "true\n");
+}
+
+#[test]
+fn to_int_rejects_what_is_not_an_integer() {
+    assert_eq!(runtime_error("toInt(\"x\");"), "toInt() can't convert \"x\" to an integer");
+    assert_eq!(runtime_error("toInt(\"1.5\");"), "toInt() can't convert \"1.5\" to an integer");
+    assert_eq!(runtime_error("toInt(\"\");"), "toInt() can't convert \"\" to an integer");
+    assert_eq!(runtime_error("toInt(true);"), "toInt() expects a number or string, got bool");
+}
+
+#[test]
+fn to_float_passes_numbers_and_parses_strings() {
+    assert_eq!(output_of("print toFloat(2), toFloat(\"2.5\"), toFloat(\" 1e3 \"), toFloat(\"-.5\");"),
+               "2 2.5 1000 -0.5\n");
+}
+
+#[test]
+fn to_float_rejects_what_is_not_a_number() {
+    assert_eq!(runtime_error("toFloat(\"x\");"), "toFloat() can't convert \"x\" to a number");
+    assert_eq!(runtime_error("toFloat(\"inf\");"), "toFloat() can't convert \"inf\" to a number");
+    assert_eq!(runtime_error("toFloat(nil);"), "toFloat() expects a number or string, got nil");
+}
+
+#[test]
+fn sum_avg_min_and_max_of_a_list_of_numbers() {
+    assert_eq!(output_of("var l = [1, 2, 3, 4]; print sum(l), avg(l), minOf(l), maxOf(l);"), "10 2.5 1 4\n");
+    assert_eq!(output_of("var l = [-1.5, 7, 0]; print sum(l), minOf(l), maxOf(l); print avg([5]);"), "5.5 -1.5 7\n5\n");
+}
+
+#[test]
+fn sum_avg_min_and_max_reject_a_mixed_list() {
+    assert_eq!(runtime_error("sum([1, \"2\", 3]);"), "sum() expects a list of numbers, found string");
+    assert_eq!(runtime_error("avg([1, nil]);"), "avg() expects a list of numbers, found nil");
+    assert_eq!(runtime_error("minOf([[1]]);"), "minOf() expects a list of numbers, found list");
+    assert_eq!(runtime_error("maxOf(3);"), "maxOf() expects a list, got number");
+}
+
+#[test]
+fn sum_avg_min_and_max_reject_an_empty_list() {
+    for name in ["sum", "avg", "minOf", "maxOf"] {
+        assert_eq!(runtime_error(&format!("{}([]);", name)), format!("{}() of an empty list", name));
+    }
+}
+
+#[test]
+fn printf_formats_its_arguments_without_a_newline() {
+    assert_eq!(output_of("printf(\"%d + %d = %d\\n\", 1, 2, 3);"), "1 + 2 = 3\n");
+    assert_eq!(output_of("printf(\"%s: \", \"pi\"); printf(\"%f\", 3.14159); print \"\";"), "pi: 3.141590\n");
+    assert_eq!(output_of("printf(\"100%% of %s\", [1, \"a\"]);"), "100% of [1, \"a\"]");
+    assert_eq!(output_of("printf(\"%d\", toInt(\"1000000000000000000000000000000\"));"), "1000000000000000000000000000000");
+}
+
+#[test]
+fn printf_rejects_a_mismatched_format() {
+    assert_eq!(runtime_error("printf(\"%d and %d\", 1);"), "printf() format has more specifiers than the 1 arguments given");
+    assert_eq!(runtime_error("printf(\"%d\", 1, 2);"), "printf() got 1 arguments more than its format uses");
+    assert_eq!(runtime_error("printf(\"%d\", 1.5);"), "printf() %d expects an integer, got 1.5");
+    assert_eq!(runtime_error("printf(\"%f\", \"a\");"), "printf() expects a number, got string");
+    assert_eq!(runtime_error("printf(\"%x\", 1);"), "printf() doesn't know the specifier '%x'");
+    assert_eq!(runtime_error("printf(\"50%\");"), "printf() format ends with a lone '%'");
+    assert_eq!(runtime_error("printf();"), "Expected 1 to 255 arguments but got 0");
+}
+
+#[test]
+fn printf_prints_nothing_when_the_format_is_wrong() {
+    assert_eq!(try_run("printf(\"a%d\");").stdout, "");
+}
+
+#[test]
+fn sizeof_grows_with_the_contents() {
+    assert_eq!(output_of("print sizeof(1) == sizeof(1000000) and sizeof(1) == sizeof(nil);"), "true\n");
+    assert_eq!(output_of("print sizeof(\"abcd\") > sizeof(\"ab\"); print sizeof(\"ab\") > sizeof(\"\");"), "true\ntrue\n");
+    assert_eq!(output_of("print sizeof([1, 2, 3]) > sizeof([1, 2]); print sizeof([1, 2]) > sizeof([]);"), "true\ntrue\n");
+    assert_eq!(output_of("print sizeof([[1, 2, 3]]) > sizeof([[1]]); print sizeof([\"abc\"]) > sizeof([\"a\"]);"), "true\ntrue\n");
+}
+
+#[test]
+fn sizeof_counts_a_list_that_contains_itself_once() {
+    assert_eq!(output_of("var a = [1, 2]; var size = sizeof(a); a[0] = a; print sizeof(a) >= size;"), "true\n");
+}
+
+#[test]
+fn assert_throws_passes_when_the_call_raises_an_error() {
+    assert_eq!(output_of("fun f() { 1 / 0; } assertThrows(f); print \"ok\";"), "ok\n");
+    assert_eq!(output_of("fun f() { return -nil; } assertThrows(f); print \"ok\";"), "ok\n");
+}
+
+#[test]
+fn assert_throws_fails_when_the_call_returns() {
+    assert_eq!(
+        runtime_error("fun f() { return 1; } assertThrows(f);"),
+        "assertThrows() expected <fn f> to raise an error, but it returned 1",
+    );
+    assert_eq!(runtime_error("fun f() {} assertThrows(f);"), "assertThrows() expected <fn f> to raise an error, but it returned nil");
+}
+
+#[test]
+fn assert_throws_rejects_what_it_cannot_call_without_arguments() {
+    assert_eq!(runtime_error("assertThrows(1);"), "assertThrows() expects a function, got number");
+    assert_eq!(runtime_error("fun f(x) { 1 / 0; } assertThrows(f);"), "assertThrows() expects a function of no arguments, got <fn f>");
+}
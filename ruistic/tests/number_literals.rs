@@ -0,0 +1,108 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+mod common;
+
+use common::output_of;
+use ruistic::{try_run, Error, Scanner, TokenType};
+
+fn scan_error(src: &str) -> String {
+    match &try_run(src).errors[..] {
+        [Error::Scan { message, .. }] => message.clone(),
+        errors => panic!("expected one scan error, got {:?}", errors),
+    }
+}
+
+#[test]
+fn reads_a_number_with_a_fraction() {
+    assert_eq!(output_of("print 5.0; print 5.25; print .5;"), "5\n5.25\n0.5\n");
+}
+
+#[test]
+fn rejects_a_trailing_dot() {
+    assert_eq!(scan_error("print 5.;"), "Trailing '.' in number literal '5.' (write '5.0' or '5')");
+}
+
+#[test]
+fn leaves_a_range_after_a_number_alone() {
+    assert_eq!(output_of("for (i in 0..2) print i;"), "0\n1\n");
+}
+
+#[test]
+fn rejects_a_literal_too_large_for_a_number() {
+    assert_eq!(scan_error("print 1e400;"), "Number literal '1e400' is too large");
+}
+
+#[test]
+fn keeps_a_huge_integer_literal_exact() {
+    let digits = "9".repeat(400);
+    assert_eq!(output_of(&format!("print {};", digits)), format!("{}\n", digits));
+}
+
+#[test]
+fn warns_when_an_integer_literal_is_rounded() {
+    let mut scanner = Scanner::new("print 9007199254740993;".to_string());
+    assert!(scanner.try_scan_tokens().is_ok());
+    assert_eq!(scanner.warnings(), ["integer literal '9007199254740993' at line 1 cannot be represented \
+                                     exactly; it is rounded to 9007199254740992"]);
+    assert_eq!(output_of("print 9007199254740993;"), "9007199254740992\n");
+}
+
+#[test]
+fn does_not_warn_about_an_exact_literal() {
+    let mut scanner = Scanner::new("print 9007199254740992; print 0.1; print 007;".to_string());
+    assert!(scanner.try_scan_tokens().is_ok());
+    assert!(scanner.warnings().is_empty());
+}
+
+#[test]
+fn reads_an_exponent() {
+    assert_eq!(output_of("print 1e3; print 1.5e-2; print 2.5E+2; print .5e1; print 1E0;"),
+               "1000\n0.015\n250\n5\n1\n");
+}
+
+#[test]
+fn rejects_an_exponent_without_digits() {
+    assert_eq!(scan_error("print 2e;"), "Missing digits in the exponent of number literal '2e'");
+    assert_eq!(scan_error("print 2.5E-;"), "Missing digits in the exponent of number literal '2.5E-'");
+}
+
+#[test]
+fn reads_hex_octal_and_binary_integers() {
+    assert_eq!(output_of("print 0xFF; print 0b1010; print 0o17; print 0XfF == 255; print 0B0; print 0O777;"),
+               "255\n10\n15\ntrue\n0\n511\n");
+}
+
+#[test]
+fn rejects_a_digit_outside_the_radix() {
+    assert_eq!(scan_error("print 0xG;"), "Invalid number literal '0xG'");
+    assert_eq!(scan_error("print 0b102;"), "Invalid number literal '0b102'");
+    assert_eq!(scan_error("print 0o8;"), "Invalid number literal '0o8'");
+}
+
+#[test]
+fn rejects_a_radix_prefix_without_digits_or_too_many() {
+    assert_eq!(scan_error("print 0x;"), "Missing digits after '0x'");
+    assert_eq!(scan_error("print 0xFFFFFFFFFFFFFFFFFF;"), "Number literal '0xFFFFFFFFFFFFFFFFFF' is too large");
+}
+
+#[test]
+fn reads_digits_grouped_with_underscores() {
+    assert_eq!(output_of("print 1_000_000; print 3.141_59; print 1_000_000 == 1000000;"), "1000000\n3.14159\ntrue\n");
+}
+
+#[test]
+fn rejects_an_underscore_not_between_two_digits() {
+    assert_eq!(scan_error("print 1__0;"), "Misplaced '_' in number literal '1__0'");
+    assert_eq!(scan_error("print 1_;"), "Misplaced '_' in number literal '1_'");
+    assert_eq!(scan_error("print 1_.5;"), "Misplaced '_' in number literal '1_.5'");
+    assert_eq!(scan_error("print 0x_F;"), "Misplaced '_' in number literal '0x_F'");
+}
+
+#[test]
+fn leading_underscore_makes_an_identifier_not_a_number() {
+    let tokens = Scanner::new("_1".to_string()).try_scan_tokens().unwrap();
+    assert_eq!(tokens[0].get_type(), TokenType::IDENTIFIER);
+    assert_eq!(try_run("print _1;").errors[0].message(), "Undefined variable '_1'");
+}
@@ -0,0 +1,36 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+mod common;
+
+use common::runtime_error;
+use ruistic::{try_run, Error};
+
+#[test]
+fn arithmetic_names_the_operator_and_both_types() {
+    assert_eq!(runtime_error("1 + \"a\";"), "Unsupported operand types for '+': number and string");
+    assert_eq!(runtime_error("\"a\" - 1;"), "Unsupported operand types for '-': string and number");
+    assert_eq!(runtime_error("true * 2;"), "Unsupported operand types for '*': bool and number");
+    assert_eq!(runtime_error("nil / nil;"), "Unsupported operand types for '/': nil and nil");
+    assert_eq!(runtime_error("\"a\" * \"b\";"), "Unsupported operand types for '*': string and string");
+}
+
+#[test]
+fn comparison_uses_the_same_format() {
+    assert_eq!(runtime_error("1 < \"2\";"), "Unsupported operand types for '<': number and string");
+    assert_eq!(runtime_error("[1] >= [1];"), "Unsupported operand types for '>=': list and list");
+    assert_eq!(runtime_error("fun f() {} f > nil;"), "Unsupported operand types for '>': function and nil");
+}
+
+#[test]
+fn big_integers_report_as_numbers() {
+    assert_eq!(runtime_error("123456789012345678901234567890 + \"x\";"),
+               "Unsupported operand types for '+': number and string");
+}
+
+#[test]
+fn error_carries_the_operator_line() {
+    assert!(matches!(try_run("print 1;\nprint 1 +\n\"a\";").errors.as_slice(),
+                     [Error::Runtime { line: Some(2), .. }]));
+}
@@ -0,0 +1,27 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use ruistic::{Interpreter, Parser, Resolver, Scanner};
+
+fn run_captured(src: &str) -> String {
+    let output = Rc::new(RefCell::new(Vec::new()));
+    let tokens = Scanner::new(src.to_string()).try_scan_tokens().unwrap();
+    let statements = Parser::new(tokens).try_parse().unwrap();
+    Resolver::new().resolve(&statements).unwrap();
+    Interpreter::with_output(output.clone()).interpret(statements);
+    String::from_utf8(output.take()).unwrap()
+}
+
+#[test]
+fn captures_print_output() {
+    assert_eq!(run_captured("print 1+2;"), "3\n");
+}
+
+#[test]
+fn captures_runtime_errors_after_earlier_output() {
+    assert_eq!(run_captured("print \"a\";\nprint -\"b\";\nprint \"c\";"),
+               "a\nRuntime error: Operand of '-' must be a number, got string at line 2\n");
+}
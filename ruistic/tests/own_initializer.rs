@@ -0,0 +1,28 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+mod common;
+
+use common::output_of;
+use ruistic::{run, Error};
+
+#[test]
+fn rejects_reading_a_local_in_its_own_initializer() {
+    let errors = run("var a = 1;\n{\n    var a = a + 1;\n}").unwrap_err();
+    assert_eq!(errors, vec![Error::Resolve {
+        message: "Can't read local variable in its own initializer".to_string(),
+        line: 3,
+        column: 13,
+    }]);
+}
+
+#[test]
+fn allows_a_global_initializer_to_read_the_previous_global() {
+    assert_eq!(output_of("var a = 1;\nvar a = a + 1;\nprint a;"), "2\n");
+}
+
+#[test]
+fn allows_a_local_function_to_call_itself() {
+    assert_eq!(output_of("{\n    fun f(n) { if (n > 0) return f(n - 1) + 1; return 0; }\n    print f(3);\n}"), "3\n");
+}
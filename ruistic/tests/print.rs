@@ -0,0 +1,60 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+mod common;
+
+use common::output_of;
+use std::cell::RefCell;
+use std::rc::Rc;
+use ruistic::{try_run, Interpreter, Parser, Resolver, Scanner};
+
+#[test]
+fn separates_values_with_a_space() {
+    assert_eq!(output_of("print 1, [2, 3], \"x\";"), "1 [2, 3] x\n");
+    assert_eq!(output_of("var a = 1; print \"a is\", a, \"and a + 1 is\", a + 1;"), "a is 1 and a + 1 is 2\n");
+}
+
+#[test]
+fn prints_scalars_bare_and_lists_in_literal_syntax() {
+    assert_eq!(output_of("print \"x\", [\"x\"], [[\"y\", nil], \"a, b\"];"), "x [\"x\"] [[\"y\", nil], \"a, b\"]\n");
+    assert_eq!(output_of("print [\"say \\\"hi\\\"\"], [true, 2.5];"), "[\"say \\\"hi\\\"\"] [true, 2.5]\n");
+}
+
+#[test]
+fn write_leaves_out_the_newline() {
+    assert_eq!(output_of("write \"a\"; write \"b\", 1; print \"!\";"), "ab 1!\n");
+}
+
+#[test]
+fn write_goes_to_an_injected_writer() {
+    let output = Rc::new(RefCell::new(Vec::new()));
+    let tokens = Scanner::new("for (i in 0..3) write i, \"\";".to_string()).try_scan_tokens().unwrap();
+    let statements = Parser::new(tokens).try_parse().unwrap();
+    Resolver::new().resolve(&statements).unwrap();
+    Interpreter::with_output(output.clone()).interpret(statements);
+    assert_eq!(output.take(), b"0 1 2 ");
+}
+
+#[test]
+fn prints_nothing_if_a_value_fails() {
+    let result = try_run("print 1, -nil, 3;");
+    assert_eq!(result.stdout, "");
+    assert_eq!(result.errors.len(), 1);
+}
+
+#[test]
+fn prints_an_integral_number_without_a_fraction() {
+    assert_eq!(output_of("print 4.0; print 4.5; print -4.0; print 8 / 2; print 9 / 2;"), "4\n4.5\n-4\n4\n4.5\n");
+}
+
+#[test]
+fn prints_zero_and_negative_zero_as_0() {
+    assert_eq!(output_of("print 0.0; print -0.0; print 0 * -1;"), "0\n0\n0\n");
+}
+
+#[test]
+fn prints_a_large_integral_number_in_full() {
+    assert_eq!(output_of("print 1e21; print -1e21; print 9007199254740992.0;"),
+               "1000000000000000000000\n-1000000000000000000000\n9007199254740992\n");
+}
@@ -0,0 +1,29 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+use ruistic::{Interpreter, Parser, Resolver, Scanner};
+
+fn profile(src: &str) -> Vec<(usize, usize)> {
+    let tokens = Scanner::new(src.to_string()).try_scan_tokens().unwrap();
+    let statements = Parser::new(tokens).try_parse().unwrap();
+    Resolver::new().resolve(&statements).unwrap();
+    let mut interpreter = Interpreter::new();
+    interpreter.set_profile(true);
+    interpreter.try_interpret(statements).unwrap();
+    interpreter.line_counts()
+}
+
+#[test]
+fn counts_each_execution_of_a_loop_body() {
+    let counts = profile("var total = 0;\nfor (i in 0..5) {\n    total = total + i;\n}\n");
+    assert_eq!(counts, vec![(2, 6), (3, 5), (1, 1)]);
+}
+
+#[test]
+fn counts_nothing_when_off() {
+    let tokens = Scanner::new("print 1;".to_string()).try_scan_tokens().unwrap();
+    let mut interpreter = Interpreter::new();
+    interpreter.try_interpret(Parser::new(tokens).try_parse().unwrap()).unwrap();
+    assert!(interpreter.line_counts().is_empty());
+}
@@ -0,0 +1,30 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+use ruistic::{run, Error};
+
+#[test]
+fn rejects_redeclaring_a_variable_in_a_block() {
+    let errors = run("{\n    var a = 1;\n    var a = 2;\n}").unwrap_err();
+    assert_eq!(errors, vec![Error::Resolve {
+        message: "Already a variable with this name in this scope".to_string(),
+        line: 3,
+        column: 9,
+    }]);
+}
+
+#[test]
+fn rejects_redeclaring_a_parameter_in_the_function_body() {
+    assert!(matches!(run("fun f(a) {\n    var a = 2;\n}").unwrap_err()[..], [Error::Resolve { line: 2, .. }]));
+}
+
+#[test]
+fn allows_shadowing_in_a_nested_block() {
+    assert_eq!(run("{\n    var a = 1;\n    {\n        var a = 2;\n    }\n}"), Ok(()));
+}
+
+#[test]
+fn allows_redeclaring_a_global() {
+    assert_eq!(run("var a = 1;\nvar a = 2;"), Ok(()));
+}
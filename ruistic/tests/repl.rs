@@ -0,0 +1,110 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Types `lines` into a REPL session, followed by `exit`, and returns what it printed.
+fn session(lines: &[&str]) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_ruistic"))
+        .env("RUISTIC_PROMPT", "")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+    let mut stdin = child.stdin.take().unwrap();
+    for line in lines {
+        writeln!(stdin, "{}", line).unwrap();
+    }
+    writeln!(stdin, "exit").unwrap();
+    drop(stdin);
+    String::from_utf8(child.wait_with_output().unwrap().stdout).unwrap()
+}
+
+#[test]
+fn echoes_a_bare_expression() {
+    assert_eq!(session(&["3 * 4"]), "12\n");
+}
+
+#[test]
+fn does_not_echo_an_expression_statement() {
+    assert_eq!(session(&["3 * 4;"]), "");
+}
+
+#[test]
+fn does_not_echo_a_declaration() {
+    assert_eq!(session(&["var a = 1;", "a + 1"]), "2\n");
+}
+
+#[test]
+fn reads_an_unclosed_block_over_several_lines() {
+    assert_eq!(session(&["if (true) {", " print 1; }"]), "... 1\n");
+}
+
+#[test]
+fn reads_a_declaration_missing_its_value_over_several_lines() {
+    assert_eq!(session(&["var a =", "2;", "a"]), "... 2\n");
+}
+
+#[test]
+fn an_empty_continuation_line_runs_what_was_typed() {
+    assert_eq!(session(&["{", "", "1 + 1"]), "... 2\n");
+}
+
+#[test]
+fn quits_at_the_end_of_the_input() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_ruistic"))
+        .env("RUISTIC_PROMPT", "")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+    writeln!(child.stdin.take().unwrap(), "print 1;").unwrap();
+    assert_eq!(String::from_utf8(child.wait_with_output().unwrap().stdout).unwrap(), "1\n");
+}
+
+#[test]
+fn keeps_variables_from_one_line_to_the_next() {
+    assert_eq!(session(&["var x = 1;", "x = x + 1;", "print x;"]), "2\n");
+}
+
+#[test]
+fn a_line_that_fails_to_parse_changes_nothing() {
+    assert_eq!(session(&["var x = 1;", "x = 2; print (;", "print x;"]), "1\n");
+}
+
+#[test]
+fn a_line_that_fails_to_resolve_changes_nothing() {
+    assert_eq!(session(&["var x = 1;", "x = 2; { var a = 1; var a = 2; }", "print x;"]), "1\n");
+}
+
+#[test]
+fn echoes_strings_quoted_unlike_print() {
+    assert_eq!(session(&["\"hi\"", "print \"hi\";"]), "\"hi\"\nhi\n");
+    assert_eq!(session(&["\"say \\\"hi\\\"\""]), "\"say \\\"hi\\\"\"\n");
+}
+
+#[test]
+fn echoes_lists_in_literal_syntax_like_print() {
+    assert_eq!(session(&["[1, \"a\", [nil]]", "print [1, \"a\", [nil]];"]), "[1, \"a\", [nil]]\n[1, \"a\", [nil]]\n");
+}
+
+#[test]
+fn history_lists_entered_lines_numbered() {
+    assert_eq!(session(&["var a = 1;", "", "a + 1", ":history"]),
+               "2\n   1  var a = 1;\n   2  a + 1\n");
+}
+
+#[test]
+fn history_keeps_a_continued_statement_as_one_entry() {
+    assert_eq!(session(&["{", "print 1;", "}", ":history", ":history"]),
+               "... ... 1\n   1  {\nprint 1;\n}\n   1  {\nprint 1;\n}\n");
+}
+
+#[test]
+fn history_starts_empty() {
+    assert_eq!(session(&[":history"]), "");
+}
@@ -0,0 +1,46 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+use ruistic::{Scanner, Token};
+
+const OLD: &str = "var greeting = \"hello\nworld\";\nvar count = 1;\nprint count + 2;\n";
+
+fn scan(src: &str) -> Vec<Token> {
+    Scanner::new(src.to_string()).try_scan_tokens().unwrap()
+}
+
+fn rescan(new: &str) -> Vec<Token> {
+    Scanner::new(new.to_string()).rescan(OLD, scan(OLD)).unwrap()
+}
+
+#[test]
+fn matches_a_full_scan_after_editing_one_line() {
+    let new = OLD.replace("var count = 1;", "var counter = 10 * 2;");
+    let tokens = rescan(&new);
+    assert_eq!(tokens, scan(&new));
+    assert_eq!(tokens[..5], scan(OLD)[..5]);
+}
+
+#[test]
+fn extends_a_token_that_ends_at_the_edit() {
+    let new = OLD.replace("count + 2", "count + 25");
+    assert_eq!(rescan(&new), scan(&new));
+}
+
+#[test]
+fn rescans_an_edit_inside_a_multiline_string() {
+    let new = OLD.replace("world", "there");
+    assert_eq!(rescan(&new), scan(&new));
+}
+
+#[test]
+fn keeps_everything_when_nothing_changed() {
+    assert_eq!(rescan(OLD), scan(OLD));
+}
+
+#[test]
+fn reports_errors_in_the_rescanned_text() {
+    let new = OLD.replace("print", "print #");
+    assert!(Scanner::new(new).rescan(OLD, scan(OLD)).is_err());
+}
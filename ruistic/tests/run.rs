@@ -0,0 +1,101 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+use ruistic::{run, Error, Interpreter, Native, Parser, Resolver, Scanner, Value};
+
+#[test]
+fn runs_a_program() {
+    let src = "
+        fun fib(n) {
+            if (n < 2) return n;
+            return fib(n - 1) + fib(n - 2);
+        }
+        var total = 0;
+        for (var i = 0; i < 10; i = i + 1) total = total + fib(i);
+    ";
+    assert_eq!(run(src), Ok(()));
+}
+
+#[test]
+fn returns_every_parse_error() {
+    let errors = run("var = 1;\nprint (2;").unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert!(errors.iter().all(|error| matches!(error, Error::Parse { .. })));
+    assert_eq!(errors[1].line(), Some(2));
+}
+
+#[test]
+fn returns_the_runtime_error() {
+    let errors = run("var a = 1;\na = a + nil;").unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(errors[0], Error::Runtime { line: Some(2), .. }));
+}
+
+#[test]
+fn returns_scan_errors_without_parsing() {
+    let errors = run("var a = 1 # 2;").unwrap_err();
+    assert_eq!(errors, vec![Error::Scan { message: "Unrecognized character '#'".to_string(), line: 1 }]);
+}
+
+#[test]
+fn calls_back_into_a_script_function() {
+    let tokens = Scanner::new("fun add(a, b) { return a + b; }".to_string()).try_scan_tokens().unwrap();
+    let statements = Parser::new(tokens).try_parse().unwrap();
+    Resolver::new().resolve(&statements).unwrap();
+    let mut interpreter = Interpreter::new();
+    interpreter.try_interpret(statements).unwrap();
+
+    let tokens = Scanner::new("add".to_string()).try_scan_tokens().unwrap();
+    let add = interpreter.eval_expression(&Parser::new(tokens).parse_expression().unwrap()).unwrap();
+    let sum = interpreter.call_value(&add, vec![Value::Number(2.0), Value::Number(3.0)]).unwrap();
+    assert_eq!(sum.to_string(), "5");
+}
+
+#[test]
+fn rejects_a_call_back_with_the_wrong_number_of_arguments() {
+    let tokens = Scanner::new("fun add(a, b) { return a + b; }".to_string()).try_scan_tokens().unwrap();
+    let statements = Parser::new(tokens).try_parse().unwrap();
+    Resolver::new().resolve(&statements).unwrap();
+    let mut interpreter = Interpreter::new();
+    interpreter.try_interpret(statements).unwrap();
+
+    let tokens = Scanner::new("add".to_string()).try_scan_tokens().unwrap();
+    let add = interpreter.eval_expression(&Parser::new(tokens).parse_expression().unwrap()).unwrap();
+    let error = interpreter.call_value(&add, vec![Value::Number(2.0)]).unwrap_err();
+    assert_eq!(error, Error::Runtime { message: "Expected 2 arguments but got 1".to_string(), line: None });
+}
+
+/// A host native that applies the script function it is given twice.
+fn twice(interpreter: &mut Interpreter, arguments: &[Value]) -> Result<Value, String> {
+    let once = interpreter.call_value(&arguments[0], vec![arguments[1].clone()]).map_err(|error| error.message().to_string())?;
+    interpreter.call_value(&arguments[0], vec![once]).map_err(|error| error.message().to_string())
+}
+
+fn output_with_twice(src: &str) -> Result<String, Error> {
+    let output = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+    let mut interpreter = Interpreter::with_output(output.clone());
+    interpreter.define_native(Native::new("twice", 2, twice));
+    let tokens = Scanner::new(src.to_string()).try_scan_tokens().unwrap();
+    let statements = Parser::new(tokens).try_parse().unwrap();
+    Resolver::new().resolve(&statements).unwrap();
+    interpreter.try_interpret(statements)?;
+    Ok(String::from_utf8(output.take()).unwrap())
+}
+
+#[test]
+fn a_host_native_calls_back_into_a_script_function() {
+    assert_eq!(output_with_twice("fun double(n) { return n * 2; } print twice(double, 3);"), Ok("12\n".to_string()));
+}
+
+#[test]
+fn a_host_native_checks_its_own_arity_and_that_of_its_callback() {
+    assert_eq!(output_with_twice("twice(1);"), Err(Error::Runtime {
+        message: "Expected 2 arguments but got 1".to_string(),
+        line: Some(1),
+    }));
+    assert_eq!(output_with_twice("fun add(a, b) { return a + b; }\ntwice(add, 1);"), Err(Error::Runtime {
+        message: "Expected 2 arguments but got 1".to_string(),
+        line: Some(2),
+    }));
+}
@@ -0,0 +1,48 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+use ruistic::{try_run, Error};
+
+/// The output printed before the run stopped, and the line of its one runtime error.
+fn run_until_error(src: &str) -> (String, usize) {
+    let result = try_run(src);
+    match result.errors.as_slice() {
+        [Error::Runtime { line: Some(line), .. }] => (result.stdout, *line),
+        errors => panic!("expected one runtime error, got {:?}", errors),
+    }
+}
+
+#[test]
+fn error_in_the_second_of_three_statements_stops_the_third() {
+    assert_eq!(run_until_error("print 1;\nprint -nil;\nprint 3;"), ("1\n".to_string(), 2));
+}
+
+#[test]
+fn error_in_a_block_stops_the_rest_of_the_block_and_program() {
+    let src = "{\n  print 1;\n  print -nil;\n  print 3;\n}\nprint 4;";
+    assert_eq!(run_until_error(src), ("1\n".to_string(), 3));
+}
+
+#[test]
+fn error_in_a_function_unwinds_through_the_caller() {
+    let src = "fun f() {\n  print 1;\n  nil();\n  print 2;\n}\nf();\nprint 3;";
+    assert_eq!(run_until_error(src), ("1\n".to_string(), 3));
+}
+
+#[test]
+fn error_in_a_loop_body_ends_the_loop() {
+    let src = "for (var i = 0; i < 3; i = i + 1) {\n  print i;\n  if (i == 1) i + nil;\n}\nprint \"done\";";
+    assert_eq!(run_until_error(src), ("0\n1\n".to_string(), 3));
+}
+
+#[test]
+fn undefined_variable_stops_the_statements_after_it() {
+    assert_eq!(run_until_error("print missing;\nprint 2;"), ("".to_string(), 1));
+}
+
+#[test]
+fn error_in_a_condition_stops_the_statements_after_it() {
+    assert_eq!(run_until_error("if (missing) print 1;\nprint 2;"), ("".to_string(), 1));
+    assert_eq!(run_until_error("print 1;\nwhile (nil < 1) print 2;\nprint 3;"), ("1\n".to_string(), 2));
+}
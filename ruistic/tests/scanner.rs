@@ -0,0 +1,118 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+use ruistic::{try_run, Error, Expr, Parser, Scanner, TokenType, Value};
+
+fn types(src: &str) -> Vec<TokenType> {
+    Scanner::new(src.to_string()).try_scan_tokens().unwrap().iter().map(|token| token.get_type()).collect()
+}
+
+#[test]
+fn comment_at_the_end_of_the_file_scans_to_eof() {
+    use TokenType::*;
+    assert_eq!(types("print 1; // comment"), vec![PRINT, NUMBER, SEMICOLON, EOF]);
+    assert_eq!(types("// only a comment"), vec![EOF]);
+    assert_eq!(types("//"), vec![EOF]);
+}
+
+#[test]
+fn comment_ends_at_the_newline() {
+    use TokenType::*;
+    assert_eq!(types("1 // one\n2 //\n// three\n"), vec![NUMBER, NUMBER, EOF]);
+    let tokens = Scanner::new("// a\n// b\nx".to_string()).try_scan_tokens().unwrap();
+    assert_eq!(tokens[0].get_line(), 3);
+}
+
+#[test]
+fn string_literal_keeps_multibyte_characters() {
+    let tokens = Scanner::new("\"héllo 😀 日本\"".to_string()).try_scan_tokens().unwrap();
+    assert_eq!(tokens[0].get_lexeme(), "\"héllo 😀 日本\"");
+    assert_eq!(tokens[0].clone().get_literal(), Some(Value::String("héllo 😀 日本".to_string().into())));
+    assert_eq!(try_run("print \"😀\" + \"é\";").stdout, "😀é\n");
+}
+
+#[test]
+fn multibyte_characters_count_as_one_column() {
+    let tokens = Scanner::new("\"😀é\" + x; // ✓✓✓\ny".to_string()).try_scan_tokens().unwrap();
+    let positions: Vec<(&str, usize, usize)> = tokens.iter()
+        .map(|token| (token.get_lexeme(), token.get_line(), token.get_column()))
+        .collect();
+    assert_eq!(positions, vec![("\"😀é\"", 1, 1), ("+", 1, 6), ("x", 1, 8), (";", 1, 9), ("y", 2, 1), ("", 2, 2)]);
+}
+
+#[test]
+fn missing_semicolon_is_reported_at_its_column() {
+    let errors = try_run("var a = 1 print a;").errors;
+    assert_eq!(errors, vec![Error::Parse { message: "Expect ';' after value.".to_string(), line: 1, column: 11 }]);
+    assert_eq!(errors[0].report(), "Parsing error: Expect ';' after value. at line 1, column 11");
+
+    let errors = try_run("print 1;\n  print 2 print 3;").errors;
+    assert_eq!(errors, vec![Error::Parse { message: "Expect ';' after value.".to_string(), line: 2, column: 11 }]);
+}
+
+fn string_literal(src: &str) -> Option<Value> {
+    Scanner::new(src.to_string()).try_scan_tokens().unwrap()[0].clone().get_literal()
+}
+
+#[test]
+fn processes_each_escape_sequence() {
+    for (escape, value) in [("\\n", "\n"), ("\\t", "\t"), ("\\r", "\r"), ("\\\\", "\\"), ("\\\"", "\""), ("\\0", "\0")] {
+        assert_eq!(string_literal(&format!("\"a{}b\"", escape)), Some(Value::String(format!("a{}b", value).into())),
+                   "escape {}", escape);
+    }
+    assert_eq!(try_run("print \"one\\ttwo\\nthree \\\"four\\\"\";").stdout, "one\ttwo\nthree \"four\"\n");
+}
+
+#[test]
+fn rejects_an_unknown_escape_sequence() {
+    let errors = Scanner::new("print \"ok\";\nprint \"a\\qb\"; print 1;".to_string()).try_scan_tokens().unwrap_err();
+    assert_eq!(errors, vec![Error::Scan { message: "Invalid escape sequence '\\q'".to_string(), line: 2 }]);
+}
+
+#[test]
+fn tracks_lines_through_a_multiline_string() {
+    let tokens = Scanner::new("\"a\nb\\n\nc\" x".to_string()).try_scan_tokens().unwrap();
+    assert_eq!(tokens[0].clone().get_literal(), Some(Value::String("a\nb\n\nc".to_string().into())));
+    assert_eq!((tokens[1].get_lexeme(), tokens[1].get_line()), ("x", 3));
+}
+
+#[test]
+fn source_over_the_token_limit_is_an_error() {
+    let errors = Scanner::new("print 1;\nprint 2;".to_string()).with_max_tokens(3).try_scan_tokens().unwrap_err();
+    assert_eq!(errors, vec![Error::Scan { message: "Source exceeds the limit of 3 tokens".to_string(), line: 2 }]);
+}
+
+#[test]
+fn source_within_the_token_limit_scans() {
+    let tokens = Scanner::new("print 1;".to_string()).with_max_tokens(3).try_scan_tokens().unwrap();
+    assert_eq!(tokens.len(), 4);
+}
+
+#[test]
+fn leading_dot_number_is_a_fraction() {
+    let tokens = Scanner::new(".5".to_string()).try_scan_tokens().unwrap();
+    assert_eq!(tokens[0].get_type(), TokenType::NUMBER);
+    assert_eq!(tokens[0].get_lexeme(), ".5");
+    assert_eq!(try_run("print .5 == 0.5; print -.25; print .5 + .5;").stdout, "true\n-0.25\n1\n");
+}
+
+#[test]
+fn unary_plus_keeps_a_number() {
+    assert_eq!(try_run("print +3; print +-3; print 1 + +2;").stdout, "3\n-3\n3\n");
+    assert_eq!(try_run("print +\"a\";").errors[0].message(), "Operand of '+' must be a number, got string");
+}
+
+#[test]
+fn dot_before_a_name_is_property_access() {
+    use TokenType::*;
+    assert_eq!(types("obj.field"), vec![IDENTIFIER, DOT, IDENTIFIER, EOF]);
+    let tokens = Scanner::new("obj.field".to_string()).try_scan_tokens().unwrap();
+    match Parser::new(tokens).parse_expression().unwrap() {
+        Expr::Get { object, name } => {
+            assert_eq!(object.to_string(), "obj");
+            assert_eq!(name.get_lexeme(), "field");
+        },
+        other => panic!("expected a property access, got {}", other),
+    }
+}
@@ -0,0 +1,166 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use ruistic::{Error, Interpreter, Parser, Resolver, Scanner};
+
+fn run_in(interpreter: &mut Interpreter, src: &str) -> Result<(), Error> {
+    let statements = Parser::new(Scanner::new(src.to_string()).try_scan_tokens().unwrap()).try_parse().unwrap();
+    Resolver::new().resolve(&statements).unwrap();
+    interpreter.try_interpret(statements)
+}
+
+fn interpreter() -> (Interpreter, Rc<RefCell<Vec<u8>>>) {
+    let output = Rc::new(RefCell::new(Vec::new()));
+    (Interpreter::with_output(output.clone()), output)
+}
+
+#[test]
+fn an_error_in_nested_blocks_leaves_the_global_scope() {
+    let (mut interpreter, output) = interpreter();
+    run_in(&mut interpreter, "var x = \"global\";").unwrap();
+    let src = "{ var x = \"outer\"; { var x = \"inner\"; { print x; nil(); } } }";
+    assert!(run_in(&mut interpreter, src).is_err());
+    assert_eq!(interpreter.scope_depth(), 0);
+    run_in(&mut interpreter, "print x;").unwrap();
+    assert_eq!(String::from_utf8(output.take()).unwrap(), "inner\nglobal\n");
+}
+
+#[test]
+fn an_error_in_a_function_leaves_the_global_scope() {
+    let (mut interpreter, _) = interpreter();
+    let src = "fun f(n) { { if (n == 0) -nil; f(n - 1); } } f(3);";
+    assert!(run_in(&mut interpreter, src).is_err());
+    assert_eq!(interpreter.scope_depth(), 0);
+}
+
+#[test]
+fn returning_and_breaking_out_of_nested_blocks_leaves_the_global_scope() {
+    let (mut interpreter, output) = interpreter();
+    let src = "
+        var x = 1;
+        fun f() { { var x = 2; { return x; } } }
+        print f();
+        while (true) { var x = 3; { var x = 4; break; } }
+        for (i in 0..3) { var x = 5; { continue; } }
+    ";
+    run_in(&mut interpreter, src).unwrap();
+    assert_eq!(interpreter.scope_depth(), 0);
+    run_in(&mut interpreter, "print x;").unwrap();
+    assert_eq!(String::from_utf8(output.take()).unwrap(), "2\n1\n");
+}
+
+#[test]
+fn an_inner_block_shadows_an_outer_variable_without_changing_it() {
+    let (mut interpreter, output) = interpreter();
+    let src = "
+        {
+            var a = \"outer\";
+            {
+                var a = \"inner\";
+                a = \"inner, assigned\";
+                print a;
+            }
+            print a;
+        }
+    ";
+    run_in(&mut interpreter, src).unwrap();
+    assert_eq!(String::from_utf8(output.take()).unwrap(), "inner, assigned\nouter\n");
+}
+
+#[test]
+fn reassignment_goes_to_the_resolved_variable_every_time() {
+    let (mut interpreter, output) = interpreter();
+    let src = "
+        fun total(n) {
+            var sum = 0;
+            for (var i = 0; i < n; i = i + 1) {
+                var sum2 = sum;
+                { sum = sum2 + i; }
+            }
+            return sum;
+        }
+        print total(5), total(200);
+        fun counter() {
+            var count = 0;
+            fun next() { count = count + 1; return count; }
+            return next;
+        }
+        var a = counter();
+        var b = counter();
+        a(); a();
+        print a(), b();
+    ";
+    run_in(&mut interpreter, src).unwrap();
+    assert_eq!(String::from_utf8(output.take()).unwrap(), "10 19900\n3 1\n");
+}
+
+#[test]
+fn assigning_a_shadowed_name_writes_the_innermost_variable() {
+    let (mut interpreter, output) = interpreter();
+    let src = "
+        var a = \"global\";
+        {
+            var a = \"outer\";
+            {
+                var a = \"inner\";
+                a = \"inner, assigned\";
+                print a;
+            }
+            a = \"outer, assigned\";
+            print a;
+        }
+        print a;
+    ";
+    run_in(&mut interpreter, src).unwrap();
+    assert_eq!(String::from_utf8(output.take()).unwrap(), "inner, assigned\nouter, assigned\nglobal\n");
+}
+
+#[test]
+fn a_closure_assigns_the_variable_it_captured() {
+    let (mut interpreter, output) = interpreter();
+    let src = "
+        var x = \"global\";
+        fun outer() {
+            var x = \"captured\";
+            fun set() { x = \"set by closure\"; }
+            {
+                var x = \"shadow\";
+                set();
+                print x;
+            }
+            print x;
+        }
+        outer();
+        print x;
+    ";
+    run_in(&mut interpreter, src).unwrap();
+    assert_eq!(String::from_utf8(output.take()).unwrap(), "shadow\nset by closure\nglobal\n");
+}
+
+#[test]
+fn assigning_a_global_from_a_function_writes_the_global() {
+    let (mut interpreter, output) = interpreter();
+    let src = "
+        var g = 1;
+        fun set() { g = 2; }
+        fun shadowed() { var g = 3; g = 4; }
+        set();
+        shadowed();
+        print g;
+    ";
+    run_in(&mut interpreter, src).unwrap();
+    run_in(&mut interpreter, "g = g + 1; print g;").unwrap();
+    assert_eq!(String::from_utf8(output.take()).unwrap(), "2\n3\n");
+}
+
+#[test]
+fn assigning_an_undeclared_global_is_an_error() {
+    let (mut interpreter, _) = interpreter();
+    assert_eq!(run_in(&mut interpreter, "fun f() { missing = 1; } f();"), Err(Error::Runtime {
+        message: "Undefined variable 'missing'".to_string(),
+        line: Some(1),
+    }));
+}
@@ -0,0 +1,90 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+mod common;
+
+use common::output_of;
+use ruistic::try_run;
+
+fn parse_error(src: &str) -> String {
+    try_run(src).errors.first().expect("expected an error").message().to_string()
+}
+
+const NAME: &str = "
+    fun name(n) {
+        switch (n) {
+            case 1:
+                return \"one\";
+            case 1 + 1:
+                return \"two\";
+            default:
+                return \"many\";
+        }
+    }
+";
+
+#[test]
+fn runs_the_first_matching_case() {
+    assert_eq!(output_of(&format!("{} print name(1), name(2);", NAME)), "one two\n");
+}
+
+#[test]
+fn runs_the_default_when_no_case_matches() {
+    assert_eq!(output_of(&format!("{} print name(3);", NAME)), "many\n");
+}
+
+#[test]
+fn runs_nothing_when_no_case_matches_and_there_is_no_default() {
+    assert_eq!(output_of("switch (3) { case 1: print 1; case 2: print 2; } print \"done\";"), "done\n");
+}
+
+#[test]
+fn does_not_fall_through() {
+    assert_eq!(output_of("switch (1) { case 1: print 1; case 2: print 2; default: print 3; }"), "1\n");
+}
+
+#[test]
+fn matches_with_equality() {
+    let src = "switch (\"b\") { case \"a\": print 1; case \"b\": print 2; case nil: print 3; }";
+    assert_eq!(output_of(src), "2\n");
+    assert_eq!(output_of("switch (nil) { case false: print 1; case nil: print 2; }"), "2\n");
+}
+
+#[test]
+fn evaluates_the_subject_once_and_cases_until_one_matches() {
+    let src = "
+        var calls = 0;
+        fun next() { calls += 1; return calls; }
+        fun check(n) { print \"case\", n; return n; }
+        switch (next()) { case check(0): print 0; case check(1): print 1; case check(2): print 2; }
+        print calls;
+    ";
+    assert_eq!(output_of(src), "case 0\ncase 1\n1\n1\n");
+}
+
+#[test]
+fn gives_each_arm_its_own_scope() {
+    let src = "
+        var a = \"outer\";
+        switch (1) { case 1: var a = \"inner\"; print a; default: var a = 0; }
+        print a;
+    ";
+    assert_eq!(output_of(src), "inner\nouter\n");
+}
+
+#[test]
+fn breaks_out_of_the_enclosing_loop() {
+    let src = "for (i in 0..5) { switch (i) { case 2: break; default: print i; } }";
+    assert_eq!(output_of(src), "0\n1\n");
+}
+
+#[test]
+fn rejects_a_second_default() {
+    assert_eq!(parse_error("switch (1) { default: print 1; default: print 2; }"), "A switch can't have more than one 'default'");
+}
+
+#[test]
+fn rejects_statements_before_the_first_case() {
+    assert_eq!(parse_error("switch (1) { print 1; }"), "Expected 'case' or 'default' in switch.");
+}
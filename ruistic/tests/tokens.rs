@@ -0,0 +1,44 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn dump(src: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_ruistic"))
+        .args(["--tokens", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(src.as_bytes()).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn prints_each_token_without_running() {
+    let output = dump("1 + 2;");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "NUMBER \"1\" 1 line 1\nPLUS \"+\" line 1\nNUMBER \"2\" 2 line 1\nSEMICOLON \";\" line 1\nEOF \"\" line 1\n",
+    );
+}
+
+#[test]
+fn prints_string_literals_and_lines() {
+    let output = dump("print\n\"hi\";");
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "PRINT \"print\" line 1\nSTRING \"\\\"hi\\\"\" hi line 2\nSEMICOLON \";\" line 2\nEOF \"\" line 2\n",
+    );
+}
+
+#[test]
+fn reports_scan_errors_instead() {
+    let output = dump("1 # 2;");
+    assert_eq!(output.status.code(), Some(65));
+    assert!(output.stdout.is_empty());
+}
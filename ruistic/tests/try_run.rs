@@ -0,0 +1,32 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+use ruistic::{try_run, Error, RunResult};
+
+#[test]
+fn captures_output_up_to_a_runtime_error() {
+    let result = try_run("print \"before\";\nprint 1 + 2;\nprint -\"x\";\nprint \"after\";");
+    assert_eq!(result, RunResult {
+        stdout: "before\n3\n".to_string(),
+        stderr: "Runtime error: Operand of '-' must be a number, got string at line 3\n".to_string(),
+        errors: vec![Error::Runtime { message: "Operand of '-' must be a number, got string".to_string(), line: Some(3) }],
+    });
+}
+
+#[test]
+fn captures_every_parse_error_without_running() {
+    let result = try_run("print \"never\";\nvar = 1;\nprint (2;");
+    assert_eq!(result.stdout, "");
+    assert_eq!(result.errors.len(), 2);
+    assert_eq!(result.stderr.lines().count(), 2);
+    assert!(result.stderr.lines().all(|line| line.starts_with("Parsing error: ")));
+}
+
+#[test]
+fn captures_output_of_a_clean_run() {
+    let result = try_run("for (i in 1..=3) print i * i;");
+    assert_eq!(result.stdout, "1\n4\n9\n");
+    assert_eq!(result.stderr, "");
+    assert!(result.errors.is_empty());
+}
@@ -0,0 +1,41 @@
+// Copyright (c) 2025 NorthernL1ghts
+// This file is part of Ruistic, a custom programming language interpreter.
+// See LICENSE file for license information.
+
+use ruistic::{try_run, Error};
+
+/// The message and line of the first parse error of `src`.
+fn first_parse_error(src: &str) -> (String, usize) {
+    match try_run(src).errors.first() {
+        Some(Error::Parse { message, line, .. }) => (message.clone(), *line),
+        errors => panic!("expected a parse error, got {:?}", errors),
+    }
+}
+
+#[test]
+fn names_the_line_of_an_unclosed_grouping() {
+    assert_eq!(first_parse_error("print (1 +\n2\n;"),
+               ("Expect ')' after expression. (unmatched '(' opened at line 1)".to_string(), 3));
+}
+
+#[test]
+fn names_the_line_of_an_unclosed_call() {
+    assert_eq!(first_parse_error("fun f(a, b) {}\nf(1,\n  2;"),
+               ("Expect ')' after arguments. (unmatched '(' opened at line 2)".to_string(), 3));
+}
+
+#[test]
+fn names_the_line_of_an_unclosed_condition() {
+    assert_eq!(first_parse_error("if (true\nprint 1;"),
+               ("Expected ')' after 'if' condition (unmatched '(' opened at line 1)".to_string(), 2));
+    assert_eq!(first_parse_error("var x = 0;\nwhile (x < 1 {}"),
+               ("Expected ')' after 'while' condition (unmatched '(' opened at line 2)".to_string(), 2));
+}
+
+#[test]
+fn names_the_innermost_unclosed_paren() {
+    assert_eq!(first_parse_error("print ((1\n+ 2);"),
+               ("Expect ')' after expression. (unmatched '(' opened at line 1)".to_string(), 2));
+    assert_eq!(first_parse_error("print (1 +\n(2 * 3;"),
+               ("Expect ')' after expression. (unmatched '(' opened at line 2)".to_string(), 2));
+}